@@ -0,0 +1,106 @@
+//! Click a moving, rotating, stretching plane: the markers are parented to the plane, so they
+//! stick to the surface where it was hit and move (and stretch) along with it.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{
+    Face, HitMarker, IntersectPlanePlugin, IntersectPlaneSet, MainCamera, MarkerSpawner, MyPlane,
+};
+
+const PLANE_SIZE: f32 = 2.0;
+const MARKER_SIZE: f32 = 0.1;
+
+/// Tags the animated plane
+#[derive(Component)]
+struct Animated;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        // move the plane before picking, so picks see where the plane is this frame
+        .add_systems(Update, animate.before(IntersectPlaneSet::Pick))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // plane
+    let plane = MyPlane::new(PLANE_SIZE);
+    commands.spawn((
+        plane,
+        Animated,
+        PbrBundle {
+            mesh: meshes.add(plane.to_plane().into()),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.6, 0.55, 0.3),
+                // so the plane (and the markers hit from below it) can be seen from both sides
+                double_sided: true,
+                cull_mode: None,
+                ..default()
+            }),
+            ..default()
+        },
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+
+    // spawn the markers as children of the plane, at the hit in the plane's local space
+    let mesh = meshes.add(Mesh::from(shape::Cube { size: MARKER_SIZE }));
+    let material = materials.add(Color::rgb(0.1, 0.8, 0.2).into());
+    commands.insert_resource(MarkerSpawner::new(move |commands, hit| {
+        if !hit.inside {
+            return;
+        }
+        // the uv runs from (0, 0) to (1, 1) across the plane's local x and z axes, see `MyPlane`,
+        // whatever its scale, which the marker inherits as the plane's child
+        let local = (hit.uv - 0.5) * PLANE_SIZE;
+        // rest the marker on whichever side of the plane was clicked
+        let side = match hit.face {
+            Face::Front => 1.0,
+            Face::Back => -1.0,
+        };
+        let marker = commands
+            .spawn((
+                PbrBundle {
+                    mesh: mesh.clone(),
+                    material: material.clone(),
+                    transform: Transform::from_xyz(local.x, side * MARKER_SIZE * 0.5, local.y),
+                    ..default()
+                },
+                HitMarker,
+            ))
+            .id();
+        commands.entity(hit.entity).add_child(marker);
+    }));
+}
+
+/// Sway the plane from side to side while tilting, spinning and stretching it.
+fn animate(time: Res<Time>, mut q_plane: Query<&mut Transform, With<Animated>>) {
+    let t = time.elapsed_seconds();
+    for mut transform in q_plane.iter_mut() {
+        transform.translation = Vec3::new((t * 0.5).sin(), 0.25 * (t * 0.7).sin(), 0.0);
+        transform.rotation =
+            Quat::from_rotation_y(t * 0.3) * Quat::from_rotation_x(0.4 * (t * 0.6).sin());
+        let stretch = 0.3 * (t * 0.4).sin();
+        transform.scale = Vec3::new(1.0 + stretch, 1.0, 1.0 - stretch);
+    }
+}
@@ -0,0 +1,92 @@
+//! Click a checkerboard: `CustomBounds` only counts hits on the light squares as inside, so only
+//! those get green markers.
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+use bevy_intersect_plane::{CustomBounds, IntersectPlanePlugin, MainCamera, MyPlane};
+
+/// Squares along each side of the board
+const SQUARES: u32 = 8;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// Whether the square at `uv` is a light one.
+fn is_light(uv: Vec2) -> bool {
+    let square = (uv * SQUARES as f32).floor().as_ivec2();
+    (square.x + square.y) % 2 == 0
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    // one texel per square, drawn with nearest sampling so the squares stay sharp
+    let data = (0..SQUARES * SQUARES)
+        .flat_map(|i| {
+            let uv = (Vec2::new((i % SQUARES) as f32, (i / SQUARES) as f32) + 0.5) / SQUARES as f32;
+            if is_light(uv) {
+                [230, 230, 220, 255]
+            } else {
+                [40, 40, 50, 255]
+            }
+        })
+        .collect();
+    let mut board = Image::new(
+        Extent3d {
+            width: SQUARES,
+            height: SQUARES,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    board.sampler_descriptor = ImageSampler::nearest();
+
+    let plane = MyPlane::new(4.0);
+    commands.spawn((
+        plane,
+        PbrBundle {
+            mesh: meshes.add(plane.to_plane().into()),
+            material: materials.add(StandardMaterial {
+                base_color_texture: Some(images.add(board)),
+                ..default()
+            }),
+            ..default()
+        },
+        // checked for every hit, so it only does a little arithmetic
+        CustomBounds::new(|uv| {
+            (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y) && is_light(uv)
+        }),
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 5.0, 3.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
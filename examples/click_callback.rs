@@ -0,0 +1,68 @@
+//! Click a plane to cycle its color, using an `OnPlaneClick` callback.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{IntersectPlanePlugin, MainCamera, MyPlane, OnPlaneClick};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let colors: Vec<Handle<StandardMaterial>> = [
+        Color::rgb(0.6, 0.55, 0.3),
+        Color::rgb(0.3, 0.5, 0.7),
+        Color::rgb(0.7, 0.3, 0.4),
+    ]
+    .into_iter()
+    .map(|color| materials.add(color.into()))
+    .collect();
+
+    for (i, x) in [-1.2, 1.2].into_iter().enumerate() {
+        let plane = MyPlane::new(2.0);
+        let colors = colors.clone();
+        // the callback only gets `Commands`, so it keeps its own count of which color is showing
+        let next = AtomicUsize::new(i + 1);
+        commands.spawn((
+            plane,
+            PbrBundle {
+                mesh: meshes.add(plane.to_plane().into()),
+                material: colors[i].clone(),
+                transform: Transform::from_xyz(x, 0.0, 0.0),
+                ..default()
+            },
+            OnPlaneClick::new(move |hit, commands| {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                commands
+                    .entity(hit.entity)
+                    .insert(colors[index % colors.len()].clone());
+            }),
+        ));
+    }
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 4.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
@@ -0,0 +1,233 @@
+//! A tiny debug console: type a command like `pick 0,10,0 dir 0,-1,0` and press Enter to pick
+//! along that ray with `PlanePicker::pick_from_world_ray`. The hits are logged, and the last ray
+//! is drawn.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{IntersectPlanePlugin, MainCamera, MyPlane, PlanePicker};
+
+/// The command being typed
+#[derive(Resource, Default)]
+struct Console {
+    line: String,
+    /// The ray of the last command that parsed
+    ray: Option<Ray>,
+}
+
+/// Tags the text showing the command being typed
+#[derive(Component)]
+struct ConsoleText;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .init_resource::<Console>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (type_command, show_command, draw_ray))
+        .run();
+}
+
+/// Parse `pick x,y,z dir x,y,z` into the ray's origin and direction.
+fn parse_command(line: &str) -> Option<(Vec3, Vec3)> {
+    let vec3 = |text: &str| {
+        let mut parts = text.split(',').map(|part| part.trim().parse::<f32>().ok());
+        let vec = Vec3::new(parts.next()??, parts.next()??, parts.next()??);
+        parts.next().is_none().then_some(vec)
+    };
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["pick", origin, "dir", direction] => Some((vec3(origin)?, vec3(direction)?)),
+        _ => None,
+    }
+}
+
+/// Two planes stacked above each other, so a ray straight down hits both, with their colors.
+fn stacked_planes() -> impl Iterator<Item = (MyPlane, Transform, Color)> {
+    [
+        (0.0, Color::rgb(0.3, 0.5, 0.3)),
+        (2.0, Color::rgb(0.3, 0.5, 0.7)),
+    ]
+    .into_iter()
+    .map(|(y, color)| {
+        (
+            MyPlane::new(4.0 - y),
+            Transform::from_xyz(0.0, y, 0.0),
+            color,
+        )
+    })
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (plane, transform, color) in stacked_planes() {
+        commands.spawn((
+            plane,
+            PbrBundle {
+                mesh: meshes.add(plane.to_plane().into()),
+                material: materials.add(color.into()),
+                transform,
+                ..default()
+            },
+        ));
+    }
+    // the console
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(12.0),
+            bottom: Val::Px(12.0),
+            ..default()
+        }),
+        ConsoleText,
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(5.0, 6.0, 8.0).looking_at(Vec3::Y, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn type_command(
+    picker: PlanePicker,
+    mut console: ResMut<Console>,
+    mut characters: EventReader<ReceivedCharacter>,
+    keys: Res<Input<KeyCode>>,
+) {
+    for character in characters.iter() {
+        if !character.char.is_control() {
+            console.line.push(character.char);
+        }
+    }
+    if keys.just_pressed(KeyCode::Back) {
+        console.line.pop();
+    }
+    if !keys.just_pressed(KeyCode::Return) {
+        return;
+    }
+    let line = std::mem::take(&mut console.line);
+    let Some((origin, direction)) = parse_command(&line) else {
+        warn!("couldn't parse `{line}`, try `pick 0,10,0 dir 0,-1,0`");
+        return;
+    };
+    let hits = picker.pick_from_world_ray(origin, direction);
+    info!("`{line}`: {} hits", hits.len());
+    for hit in &hits {
+        info!(
+            "  {:?} at {:.2}, uv {:.2}, inside: {}",
+            hit.entity, hit.world, hit.uv, hit.inside
+        );
+    }
+    console.ray = Some(Ray {
+        origin,
+        direction: direction.normalize_or_zero(),
+    });
+}
+
+fn show_command(console: Res<Console>, mut q_text: Query<&mut Text, With<ConsoleText>>) {
+    if !console.is_changed() {
+        return;
+    }
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = format!("> {}", console.line);
+    }
+}
+
+fn draw_ray(console: Res<Console>, mut gizmos: Gizmos) {
+    if let Some(ray) = console.ray {
+        gizmos.ray(ray.origin, ray.direction * 20.0, Color::YELLOW);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{input::InputPlugin, render::camera::ManualTextureViews};
+
+    use super::*;
+
+    #[test]
+    fn commands_parse_into_rays() {
+        assert_eq!(
+            parse_command("pick 0,10,0 dir 0,-1,0"),
+            Some((Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Y))
+        );
+        assert_eq!(
+            parse_command("  pick 1.5,2,-3   dir 0,0,-1 "),
+            Some((Vec3::new(1.5, 2.0, -3.0), Vec3::NEG_Z))
+        );
+        for line in [
+            "",
+            "pick 0,10,0",
+            "pick 0,10 dir 0,-1,0",
+            "pick 0,10,0,1 dir 0,-1,0",
+            "pick a,b,c dir 0,-1,0",
+            "aim 0,10,0 dir 0,-1,0",
+        ] {
+            assert_eq!(parse_command(line), None, "`{line}`");
+        }
+    }
+
+    #[test]
+    fn a_command_straight_down_hits_both_planes() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            InputPlugin,
+            TransformPlugin,
+            WindowPlugin::default(),
+        ))
+        .add_asset::<Mesh>()
+        .add_asset::<StandardMaterial>()
+        .add_asset::<Image>()
+        .init_resource::<ManualTextureViews>()
+        .add_plugins(IntersectPlanePlugin::default());
+        let planes: Vec<_> = stacked_planes()
+            .map(|(plane, transform, _)| {
+                let global = GlobalTransform::from(transform);
+                app.world.spawn((plane, transform, global)).id()
+            })
+            .collect();
+        let camera = Transform::from_xyz(5.0, 6.0, 8.0).looking_at(Vec3::Y, Vec3::Y);
+        app.world.spawn((
+            Camera::default(),
+            Projection::default(),
+            camera,
+            GlobalTransform::from(camera),
+            MainCamera,
+        ));
+        app.update();
+
+        let (origin, direction) = parse_command("pick 0,10,0 dir 0,-1,0").unwrap();
+        let mut pick = IntoSystem::into_system(move |picker: PlanePicker| {
+            picker.pick_from_world_ray(origin, direction)
+        });
+        pick.initialize(&mut app.world);
+        let hits = pick.run((), &mut app.world);
+
+        // the upper plane first
+        let hits: Vec<_> = hits.iter().map(|hit| (hit.entity, hit.distance)).collect();
+        assert_eq!(hits, [(planes[1], 8.0), (planes[0], 10.0)]);
+    }
+}
@@ -0,0 +1,68 @@
+//! Pick a wall built from `shape::Quad`, whose mesh faces +Z rather than +Y like
+//! `shape::Plane`, so it stands up without turning its transform. Click it to print the uv.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{IntersectPlanePlugin, MainCamera, MyPlane, PlaneHitEvent};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_hits)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // the wall: a quad in the XY plane, facing +Z
+    let size = 3.0;
+    commands.spawn((
+        MyPlane::from_mesh_normal(size, Vec3::Z),
+        PbrBundle {
+            mesh: meshes.add(shape::Quad::new(Vec2::splat(size)).into()),
+            material: materials.add(Color::rgb(0.7, 0.5, 0.3).into()),
+            transform: Transform::from_xyz(0.0, 1.5, 0.0),
+            ..default()
+        },
+    ));
+    // a floor in front of it, an ordinary plane
+    let floor = MyPlane::new(6.0);
+    commands.spawn((
+        floor,
+        PbrBundle {
+            mesh: meshes.add(floor.to_plane().into()),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            transform: Transform::from_xyz(0.0, 0.0, 3.0),
+            ..default()
+        },
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 6.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-2.0, 2.5, 7.0)
+                .looking_at(Vec3::new(0.0, 1.0, 0.0), Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn print_hits(mut hits: EventReader<PlaneHitEvent>) {
+    for PlaneHitEvent { hit, .. } in hits.iter().filter(|event| event.hit.inside) {
+        // uv (0, 0) is the quad's top left, as in its texture
+        info!("{:?} hit at uv {:.2}", hit.entity, hit.uv);
+    }
+}
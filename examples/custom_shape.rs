@@ -0,0 +1,88 @@
+//! Pick a custom surface: a disc, registered with `register_pickable`, gets markers like the
+//! planes do.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{
+    IntersectPlanePlugin, MainCamera, MyPlane, RaycastHit, Raycastable, RegisterPickable,
+};
+
+/// A flat disc facing +Z, the way `shape::Circle` is built
+#[derive(Component)]
+struct Disc {
+    radius: f32,
+}
+
+impl Raycastable for Disc {
+    fn raycast(&self, ray: Ray, transform: &Transform) -> Option<RaycastHit> {
+        let normal = transform.back();
+        let distance = ray.intersect_plane(transform.translation, normal)?;
+        let local =
+            transform.rotation.inverse() * (ray.get_point(distance) - transform.translation);
+        Some(RaycastHit {
+            distance,
+            normal,
+            // polar coordinates: the fraction of the radius out, and of a turn around
+            uv: Vec2::new(
+                local.truncate().length() / self.radius,
+                local.y.atan2(local.x) / std::f32::consts::TAU + 0.5,
+            ),
+            inside: local.truncate().length() <= self.radius,
+        })
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .register_pickable::<Disc>()
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // a plane for comparison
+    let plane = MyPlane::new(2.0);
+    commands.spawn((
+        plane,
+        PbrBundle {
+            mesh: meshes.add(plane.to_plane().into()),
+            material: materials.add(Color::rgb(0.6, 0.55, 0.3).into()),
+            transform: Transform::from_xyz(-1.5, 0.0, 0.0),
+            ..default()
+        },
+    ));
+    // the disc, standing up
+    let radius = 1.0;
+    commands.spawn((
+        Disc { radius },
+        PbrBundle {
+            mesh: meshes.add(shape::Circle::new(radius).into()),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.7).into()),
+            transform: Transform::from_xyz(1.5, 1.0, 0.0),
+            ..default()
+        },
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 3.0, 6.0)
+                .looking_at(Vec3::new(0.0, 0.5, 0.0), Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
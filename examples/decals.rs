@@ -0,0 +1,99 @@
+//! Click the planes to leave square decals on them, each turned a little further than the last.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{
+    Decal, IntersectPlanePlugin, IntersectPlaneSet, MainCamera, MarkerSpawner, MyPlane,
+    PlaneHitEvent,
+};
+
+/// The golden angle, so successive decals never line up
+const TURN: f32 = 2.399_963;
+
+/// The mesh and material decals are drawn with
+#[derive(Resource)]
+struct DecalAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        // the decals are the markers
+        .insert_resource(MarkerSpawner::new(|_, _| {}))
+        .add_systems(Startup, setup)
+        .add_systems(Update, place_decals.after(IntersectPlaneSet::Pick))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(DecalAssets {
+        mesh: meshes.add(Decal::mesh()),
+        material: materials.add(StandardMaterial {
+            base_color: Color::rgb(0.1, 0.1, 0.1),
+            ..default()
+        }),
+    });
+
+    // a floor and a wall
+    let plane = MyPlane::new(4.0);
+    let plane_mesh = meshes.add(plane.to_plane().into());
+    for transform in [
+        Transform::IDENTITY,
+        Transform::from_xyz(0.0, 2.0, -2.0)
+            .with_rotation(Quat::from_rotation_x(90f32.to_radians())),
+    ] {
+        commands.spawn((
+            plane,
+            PbrBundle {
+                mesh: plane_mesh.clone(),
+                material: materials.add(Color::rgb(0.7, 0.65, 0.55).into()),
+                transform,
+                ..default()
+            },
+        ));
+    }
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(3.0, 4.0, 5.0)
+                .looking_at(Vec3::new(0.0, 1.0, 0.0), Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn place_decals(
+    mut commands: Commands,
+    mut hits: EventReader<PlaneHitEvent>,
+    assets: Res<DecalAssets>,
+    mut placed: Local<u32>,
+) {
+    // only the front-most inside hit, so overlapping planes don't both get one
+    let Some(PlaneHitEvent { hit, .. }) = hits.iter().find(|PlaneHitEvent { hit, .. }| hit.inside)
+    else {
+        return;
+    };
+    let decal = Decal {
+        size: 0.4,
+        rotation: *placed as f32 * TURN,
+        ..default()
+    };
+    commands.spawn(decal.bundle(hit, assets.mesh.clone(), assets.material.clone()));
+    *placed += 1;
+}
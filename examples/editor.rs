@@ -0,0 +1,209 @@
+//! A tiny scene editor: hover a tile to highlight it, click it to select it, and drag the
+//! selected tile to move it across the ground.
+//!
+//! - hover: `HoverHighlight` swaps the hovered tile's material
+//! - select: a `PlaneHitEvent` on a tile swaps in the selected material
+//! - drag: `plane_delta` turns cursor movement into movement along the ground
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{
+    plane_delta, HoverHighlight, IntersectPlanePlugin, IntersectPlaneSet, MainCamera,
+    MarkerSpawner, MyPlane, PlaneHitEvent, PlanePicker, UnhighlightedMaterial,
+};
+
+/// Tags the ground the tiles are dragged across, which can't be selected itself
+#[derive(Component)]
+struct Ground;
+
+/// The selected material, to swap in when a tile is selected
+#[derive(Resource)]
+struct SelectedMaterial(Handle<StandardMaterial>);
+
+/// On the selected tile, the material it had before it was selected
+#[derive(Component)]
+struct Selected(Handle<StandardMaterial>);
+
+/// Dragging the selected tile
+#[derive(Resource, Default)]
+struct Drag {
+    /// The cursor ray last frame, while dragging
+    ray: Option<Ray>,
+    /// How far to move the selected tile this frame
+    offset: Vec3,
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        // clicks select, so don't leave markers behind
+        .insert_resource(MarkerSpawner::new(|_, _| {}))
+        .init_resource::<Drag>()
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (select, drag, move_selected)
+                .chain()
+                .after(IntersectPlaneSet::Pick),
+        )
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // hovering swaps in this material
+    commands.insert_resource(HoverHighlight(
+        materials.add(Color::rgb(0.9, 0.8, 0.4).into()),
+    ));
+    commands.insert_resource(SelectedMaterial(
+        materials.add(Color::rgb(0.9, 0.4, 0.2).into()),
+    ));
+
+    // ground
+    let ground = MyPlane::new(8.0);
+    commands.spawn((
+        ground,
+        PbrBundle {
+            mesh: meshes.add(ground.to_plane().into()),
+            material: materials.add(Color::rgb(0.3, 0.35, 0.3).into()),
+            ..default()
+        },
+        Ground,
+    ));
+    // tiles, just above the ground so they're hit first
+    let tile = MyPlane::new(1.0);
+    let tile_mesh = meshes.add(tile.to_plane().into());
+    let tile_material = materials.add(Color::rgb(0.3, 0.5, 0.7).into());
+    for x in [-2.0, 0.0, 2.0] {
+        commands.spawn((
+            tile,
+            PbrBundle {
+                mesh: tile_mesh.clone(),
+                material: tile_material.clone(),
+                transform: Transform::from_xyz(x, 0.01, 0.0),
+                ..default()
+            },
+        ));
+    }
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 6.0, 6.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+/// Select the clicked tile, or deselect by clicking the ground.
+///
+/// The hover highlight already swapped the material of whatever is hovered, remembering the
+/// original in `UnhighlightedMaterial`. So rather than fighting it, selection changes the material
+/// the highlight restores when the hover moves on, or the current material if the tile isn't
+/// hovered.
+fn select(
+    mut commands: Commands,
+    mut hits: EventReader<PlaneHitEvent>,
+    selected_material: Res<SelectedMaterial>,
+    q_ground: Query<(), With<Ground>>,
+    q_selected: Query<(Entity, &Selected, Option<&UnhighlightedMaterial>)>,
+    q_tile: Query<(&Handle<StandardMaterial>, Option<&UnhighlightedMaterial>)>,
+) {
+    // the front-most inside hit is the one under the cursor
+    let Some(PlaneHitEvent { hit, .. }) = hits.iter().find(|PlaneHitEvent { hit, .. }| hit.inside)
+    else {
+        return;
+    };
+    if q_selected.contains(hit.entity) {
+        return;
+    }
+
+    // deselect: put back the material the tile had before being selected
+    for (entity, Selected(original), unhighlighted) in q_selected.iter() {
+        let mut entity = commands.entity(entity);
+        entity.remove::<Selected>();
+        if unhighlighted.is_some() {
+            entity.insert(UnhighlightedMaterial(original.clone()));
+        } else {
+            entity.insert(original.clone());
+        }
+    }
+
+    // select, unless the ground was clicked
+    if q_ground.contains(hit.entity) {
+        return;
+    }
+    if let Ok((material, unhighlighted)) = q_tile.get(hit.entity) {
+        let mut tile = commands.entity(hit.entity);
+        if let Some(UnhighlightedMaterial(original)) = unhighlighted {
+            tile.insert((
+                Selected(original.clone()),
+                UnhighlightedMaterial(selected_material.0.clone()),
+            ));
+        } else {
+            tile.insert((Selected(material.clone()), selected_material.0.clone()));
+        }
+    }
+}
+
+/// Work out how far to drag the selected tile across the ground, starting from a press over it.
+///
+/// The tile itself is moved by `move_selected`.
+fn drag(
+    picker: PlanePicker,
+    mouse: Res<Input<MouseButton>>,
+    mut drag: ResMut<Drag>,
+    q_ground: Query<(&Transform, &MyPlane), With<Ground>>,
+    q_selected: Query<Entity, With<Selected>>,
+) {
+    drag.offset = Vec3::ZERO;
+    let button = picker.config().pick_button;
+    if !mouse.pressed(button) {
+        drag.ray = None;
+        return;
+    }
+    let (Ok((ground, ground_plane)), Ok(selected)) =
+        (q_ground.get_single(), q_selected.get_single())
+    else {
+        return;
+    };
+    let Some(ray) = picker.cursor_ray() else {
+        return;
+    };
+    // a drag only starts from a press over the selected tile
+    if mouse.just_pressed(button) {
+        let over_selected = picker
+            .pick_closest()
+            .is_some_and(|hit| hit.entity == selected);
+        drag.ray = over_selected.then_some(ray);
+        return;
+    }
+    let Some(previous) = drag.ray else {
+        return;
+    };
+
+    // how far the point under the cursor moved across the ground, along the ground's axes
+    if let Some(delta) = plane_delta(&previous, &ray, ground, ground_plane) {
+        let (x_axis, _, z_axis) = MyPlane::axes(&ground_plane.oriented(ground));
+        drag.offset = x_axis * delta.x + z_axis * delta.y;
+    }
+    drag.ray = Some(ray);
+}
+
+fn move_selected(drag: Res<Drag>, mut q_selected: Query<&mut Transform, With<Selected>>) {
+    for mut transform in q_selected.iter_mut() {
+        transform.translation += drag.offset;
+    }
+}
@@ -0,0 +1,144 @@
+//! Pick planes through a 3D viewport shown inside an egui window: the camera renders to an
+//! image, and clicks on that image are mapped into the image's pixels and picked with
+//! `PlanePicker::pick_at_pixel`.
+//!
+//! Run with `cargo run --example egui_viewport --features egui`.
+
+use bevy::{
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+    },
+};
+use bevy_egui::{egui, EguiContexts, EguiPlugin, EguiUserTextures};
+use bevy_intersect_plane::{
+    IntersectPlanePlugin, IntersectPlaneSet, MainCamera, MyPlane, PlaneHitEvent, PlanePicker,
+};
+
+const VIEWPORT_SIZE: u32 = 512;
+
+/// The image the main camera renders to
+#[derive(Resource, Deref)]
+struct Viewport(Handle<Image>);
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, EguiPlugin, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        // before the markers are spawned, so clicks get a marker the same frame
+        .add_systems(Update, viewport_ui.before(IntersectPlaneSet::Markers))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    mut egui_textures: ResMut<EguiUserTextures>,
+) {
+    // the image the scene is rendered to
+    let size = Extent3d {
+        width: VIEWPORT_SIZE,
+        height: VIEWPORT_SIZE,
+        ..default()
+    };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    let viewport = images.add(image);
+    egui_textures.add_image(viewport.clone());
+    commands.insert_resource(Viewport(viewport.clone()));
+
+    // planes
+    for (x, color) in [
+        (-1.2, Color::rgb(0.6, 0.55, 0.3)),
+        (1.2, Color::rgb(0.3, 0.5, 0.7)),
+    ] {
+        let plane = MyPlane::new(2.0);
+        commands.spawn((
+            plane,
+            PbrBundle {
+                mesh: meshes.add(plane.to_plane().into()),
+                material: materials.add(color.into()),
+                transform: Transform::from_xyz(x, 0.0, 0.0),
+                ..default()
+            },
+        ));
+    }
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // the camera picks are made through, rendering to the image. It has no cursor of its own, so
+    // the plugin's click picking never fires for it.
+    commands.spawn((
+        Camera3dBundle {
+            camera: Camera {
+                order: -1,
+                target: RenderTarget::Image(viewport),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 4.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+    // a camera for the window, which egui draws over
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn viewport_ui(
+    mut contexts: EguiContexts,
+    viewport: Res<Viewport>,
+    picker: PlanePicker,
+    mut hit_events: EventWriter<PlaneHitEvent>,
+) {
+    let Some(texture) = contexts.image_id(&viewport) else {
+        return;
+    };
+    egui::Window::new("Viewport").show(contexts.ctx_mut(), |ui| {
+        let response = ui.add(
+            egui::Image::new(egui::load::SizedTexture::new(
+                texture,
+                egui::vec2(400.0, 400.0),
+            ))
+            .sense(egui::Sense::click()),
+        );
+        let Some(pointer) = response
+            .interact_pointer_pos()
+            .filter(|_| response.clicked())
+        else {
+            return;
+        };
+        // from egui points within the widget to pixels of the image the camera renders to
+        let fraction = (pointer - response.rect.min) / response.rect.size();
+        let pixel = Vec2::new(fraction.x, fraction.y) * VIEWPORT_SIZE as f32;
+        if let Some(hit) = picker.pick_at_pixel(pixel) {
+            info!("{:?}: hit at {:.2}", hit.entity, hit.uv);
+            hit_events.send(picker.hit_event(hit));
+        }
+    });
+}
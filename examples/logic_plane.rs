@@ -0,0 +1,79 @@
+//! A "logic plane": an invisible trigger zone with no mesh or material, which picks like any
+//! other plane. Click inside the outlined zone to trigger it.
+//!
+//! Picking only needs a `MyPlane` and a `Transform`, so the same works in a headless app.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{IntersectPlanePlugin, MainCamera, MyPlane, PlaneHitEvent};
+
+/// Tags the invisible trigger zone
+#[derive(Component)]
+struct TriggerZone;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (outline_zone, trigger))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // a visible ground plane
+    let ground = MyPlane::new(6.0);
+    commands.spawn((
+        ground,
+        PbrBundle {
+            mesh: meshes.add(ground.to_plane().into()),
+            material: materials.add(Color::rgb(0.3, 0.35, 0.3).into()),
+            ..default()
+        },
+    ));
+    // the trigger zone: no mesh, no material, just a plane and where it is
+    commands.spawn((
+        MyPlane::new(2.0),
+        TransformBundle::from_transform(Transform::from_xyz(1.0, 0.5, 0.0)),
+        TriggerZone,
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 5.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+/// Nothing renders the zone, so outline it to see where to click.
+fn outline_zone(
+    mut gizmos: Gizmos,
+    q_zone: Query<(&MyPlane, &GlobalTransform), With<TriggerZone>>,
+) {
+    for (plane, transform) in q_zone.iter() {
+        let [a, b, c, d] = plane.corners(transform);
+        gizmos.linestrip([a, b, c, d, a], Color::YELLOW);
+    }
+}
+
+fn trigger(mut hits: EventReader<PlaneHitEvent>, q_zone: Query<(), With<TriggerZone>>) {
+    for PlaneHitEvent { hit, .. } in hits.iter() {
+        if hit.inside && q_zone.contains(hit.entity) {
+            info!("triggered at {:.2}", hit.uv);
+        }
+    }
+}
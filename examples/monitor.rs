@@ -0,0 +1,158 @@
+//! An in-world monitor: a plane showing what a second camera renders into a texture. Click the
+//! screen to move the dot in the scene it shows.
+//!
+//! The plane's uvs line up with the texture's, (0, 0) at its top left, so a hit's uv is where on
+//! the render target the click landed.
+
+use bevy::{
+    core_pipeline::clear_color::ClearColorConfig,
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+    },
+};
+use bevy_intersect_plane::{IntersectPlanePlugin, MainCamera, MyPlane, PlaneHitEvent};
+
+/// The size of the render target in pixels
+const SCREEN_SIZE: u32 = 512;
+
+/// Tags the plane the render target is shown on
+#[derive(Component)]
+struct Monitor;
+
+/// Tags the dot in the scene on the monitor
+#[derive(Component)]
+struct Dot;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_dot)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    // the render target
+    let size = Extent3d {
+        width: SCREEN_SIZE,
+        height: SCREEN_SIZE,
+        ..default()
+    };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    let image = images.add(image);
+
+    // the scene on the monitor: a dot, seen by a 2d camera with a pixel per world unit
+    commands.spawn(Camera2dBundle {
+        camera_2d: Camera2d {
+            clear_color: ClearColorConfig::Custom(Color::rgb(0.05, 0.1, 0.2)),
+        },
+        camera: Camera {
+            target: RenderTarget::Image(image.clone()),
+            // drawn before the main camera, which shows it
+            order: -1,
+            ..default()
+        },
+        ..default()
+    });
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(1.0, 0.8, 0.2),
+                custom_size: Some(Vec2::splat(32.0)),
+                ..default()
+            },
+            ..default()
+        },
+        Dot,
+    ));
+
+    // the monitor, standing up and facing the camera
+    let screen = MyPlane::new(3.0);
+    commands.spawn((
+        screen,
+        PbrBundle {
+            mesh: meshes.add(screen.to_plane().into()),
+            material: materials.add(StandardMaterial {
+                base_color_texture: Some(image),
+                unlit: true,
+                ..default()
+            }),
+            transform: Transform::from_xyz(0.0, 1.5, 0.0)
+                .with_rotation(Quat::from_rotation_x(90f32.to_radians())),
+            ..default()
+        },
+        Monitor,
+    ));
+    // a floor to stand it on
+    let floor = MyPlane::new(8.0);
+    commands.spawn((
+        floor,
+        PbrBundle {
+            mesh: meshes.add(floor.to_plane().into()),
+            material: materials.add(Color::rgb(0.3, 0.3, 0.3).into()),
+            ..default()
+        },
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(1.5, 2.5, 5.0)
+                .looking_at(Vec3::new(0.0, 1.5, 0.0), Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn move_dot(
+    mut hits: EventReader<PlaneHitEvent>,
+    q_monitor: Query<(), With<Monitor>>,
+    mut q_dot: Query<&mut Transform, With<Dot>>,
+) {
+    let Some(PlaneHitEvent { hit, .. }) = hits
+        .iter()
+        .find(|PlaneHitEvent { hit, .. }| hit.inside && q_monitor.contains(hit.entity))
+    else {
+        return;
+    };
+    // uv runs down from the top left of the render target, the 2d camera's y runs up from its
+    // middle
+    let pixel = (hit.uv - 0.5) * SCREEN_SIZE as f32;
+    for mut transform in q_dot.iter_mut() {
+        transform.translation = Vec3::new(pixel.x, -pixel.y, 0.0);
+    }
+}
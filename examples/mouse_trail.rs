@@ -0,0 +1,120 @@
+//! Move the cursor over the planes to draw a trail on them: the last few cursor positions are
+//! projected onto the hovered plane with `project_screen_path` and joined up with gizmos.
+
+use std::collections::VecDeque;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_intersect_plane::{
+    project_screen_path, HoveredPlane, IntersectPlanePlugin, MainCamera, MyPlane,
+};
+
+/// How the trail is drawn
+#[derive(Resource)]
+struct TrailConfig {
+    /// How many cursor positions the trail remembers
+    length: usize,
+    color: Color,
+}
+
+/// The most recent cursor positions, in logical viewport pixels, oldest first
+#[derive(Resource, Default)]
+struct Trail(VecDeque<Vec2>);
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .insert_resource(TrailConfig {
+            length: 64,
+            color: Color::CYAN,
+        })
+        .init_resource::<Trail>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (record_trail, draw_trail).chain())
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // a floor and a wall
+    let plane = MyPlane::new(4.0);
+    let plane_mesh = meshes.add(plane.to_plane().into());
+    for transform in [
+        Transform::IDENTITY,
+        Transform::from_xyz(0.0, 2.0, -2.0)
+            .with_rotation(Quat::from_rotation_x(90f32.to_radians())),
+    ] {
+        commands.spawn((
+            plane,
+            PbrBundle {
+                mesh: plane_mesh.clone(),
+                material: materials.add(Color::rgb(0.3, 0.3, 0.35).into()),
+                transform,
+                ..default()
+            },
+        ));
+    }
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(2.0, 3.0, 6.0)
+                .looking_at(Vec3::new(0.0, 1.0, 0.0), Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn record_trail(
+    config: Res<TrailConfig>,
+    mut trail: ResMut<Trail>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Some(cursor) = q_window.get_single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    if trail.0.back() != Some(&cursor) {
+        trail.0.push_back(cursor);
+    }
+    while trail.0.len() > config.length {
+        trail.0.pop_front();
+    }
+}
+
+fn draw_trail(
+    config: Res<TrailConfig>,
+    trail: Res<Trail>,
+    hovered: Res<HoveredPlane>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    q_plane: Query<(&GlobalTransform, &MyPlane)>,
+    mut gizmos: Gizmos,
+) {
+    let (Ok((camera, camera_transform)), Some((plane_transform, plane))) = (
+        q_camera.get_single(),
+        hovered.0.and_then(|entity| q_plane.get(entity).ok()),
+    ) else {
+        return;
+    };
+    let points: Vec<Vec2> = trail.0.iter().copied().collect();
+    let projected = project_screen_path(&points, camera, camera_transform, plane_transform, plane);
+    // lift the trail a little off the surface so it isn't hidden in it
+    let lift = plane.front_normal(&plane_transform.compute_transform()) * 0.01;
+    for pair in projected.windows(2) {
+        // drop the segments where either end missed the plane
+        if let [Some(start), Some(end)] = pair {
+            gizmos.line(*start + lift, *end + lift, config.color);
+        }
+    }
+}
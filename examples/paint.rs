@@ -0,0 +1,149 @@
+//! Hold the left mouse button to paint onto a plane's texture, with a soft-edged brush.
+//!
+//! Hits are reported in texels of the plane's texture (see `CoordSpace::Texels`), so there's no
+//! conversion to do before writing pixels.
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy_intersect_plane::{
+    CoordSpace, IntersectPlanePlugin, MainCamera, MarkerSpawner, MyPlane, PlanePicker,
+};
+
+const TEXTURE_SIZE: u32 = 256;
+
+/// How the cursor paints.
+#[derive(Resource)]
+struct Brush {
+    color: Color,
+    /// In texels
+    radius: f32,
+    /// The fraction of the radius painted at full strength, from 0 (the strength falls off all
+    /// the way from the center) to 1 (a hard, aliased edge). In between, the strength falls off
+    /// smoothly towards the edge.
+    hardness: f32,
+}
+
+impl Default for Brush {
+    fn default() -> Self {
+        Self {
+            color: Color::rgb(0.2, 0.3, 0.8),
+            radius: 8.0,
+            hardness: 0.3,
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            IntersectPlanePlugin::default().coord_space(CoordSpace::Texels {
+                size: UVec2::splat(TEXTURE_SIZE),
+            }),
+        ))
+        // paint instead of spawning markers
+        .insert_resource(MarkerSpawner::new(|_, _| {}))
+        .init_resource::<Brush>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, paint)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    // canvas
+    let canvas = images.add(Image::new_fill(
+        Extent3d {
+            width: TEXTURE_SIZE,
+            height: TEXTURE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[255, 255, 255, 255],
+        TextureFormat::Rgba8UnormSrgb,
+    ));
+    let plane = MyPlane::new(4.0);
+    commands.spawn((
+        plane,
+        PbrBundle {
+            mesh: meshes.add(plane.to_plane().into()),
+            material: materials.add(StandardMaterial {
+                base_color_texture: Some(canvas),
+                unlit: true,
+                ..default()
+            }),
+            ..default()
+        },
+    ));
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 4.0, 2.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn paint(
+    picker: PlanePicker,
+    mouse: Res<Input<MouseButton>>,
+    brush: Res<Brush>,
+    q_material: Query<&Handle<StandardMaterial>>,
+    materials: Res<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if !mouse.pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(hit) = picker.pick_closest() else {
+        return;
+    };
+    let Some(image) = q_material
+        .get(hit.entity)
+        .ok()
+        .and_then(|handle| materials.get(handle))
+        .and_then(|material| material.base_color_texture.as_ref())
+        .and_then(|texture| images.get_mut(texture))
+    else {
+        return;
+    };
+    paint_dab(image, hit.coords, &brush);
+}
+
+/// Blend one dab of `brush` into `image` around `center`, in texels.
+fn paint_dab(image: &mut Image, center: Vec2, brush: &Brush) {
+    let size = image.size().as_uvec2();
+    let min = (center - brush.radius).floor().max(Vec2::ZERO).as_uvec2();
+    let max = (center + brush.radius).ceil().as_uvec2().min(size);
+    let color = brush.color.as_rgba_u8().map(f32::from);
+    for y in min.y..max.y {
+        for x in min.x..max.x {
+            // distance from the brush center to the texel center, as a fraction of the radius
+            let distance = (Vec2::new(x as f32, y as f32) + 0.5).distance(center) / brush.radius;
+            if distance >= 1.0 {
+                continue;
+            }
+            let strength = 1.0 - smoothstep(brush.hardness, 1.0, distance);
+            let index = ((y * size.x + x) * 4) as usize;
+            for (channel, target) in image.data[index..index + 4].iter_mut().zip(color) {
+                *channel = (f32::from(*channel) * (1.0 - strength) + target * strength) as u8;
+            }
+        }
+    }
+}
+
+/// 0 below `edge0`, 1 above `edge1`, and a smooth curve in between.
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    if edge0 >= edge1 {
+        return if x < edge0 { 0.0 } else { 1.0 };
+    }
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
@@ -0,0 +1,86 @@
+//! Click the plane to drop a ball onto it at the hit point.
+//!
+//! Run with `cargo run --example physics --features physics`.
+
+use bevy::prelude::*;
+use bevy_intersect_plane::{HitMarker, IntersectPlanePlugin, MainCamera, MarkerSpawner, MyPlane};
+use bevy_rapier3d::prelude::*;
+
+/// How far above the hit the ball is dropped from
+const DROP_HEIGHT: f32 = 1.0;
+const BALL_RADIUS: f32 = 0.1;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            IntersectPlanePlugin::default(),
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // plane
+    let plane = MyPlane::new(3.0);
+    commands.spawn((
+        plane,
+        PbrBundle {
+            mesh: meshes.add(plane.to_plane().into()),
+            material: materials.add(Color::rgb(0.6, 0.55, 0.3).into()),
+            ..default()
+        },
+        // a thin box matching the plane, so the balls have something to land on
+        Collider::cuboid(plane.size * 0.5, 0.01, plane.size * 0.5),
+    ));
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+
+    // instead of the default cube marker, drop a ball above every hit within the plane
+    let mesh = meshes.add(
+        shape::UVSphere {
+            radius: BALL_RADIUS,
+            ..default()
+        }
+        .into(),
+    );
+    let material = materials.add(Color::rgb(0.1, 0.8, 0.2).into());
+    commands.insert_resource(MarkerSpawner::new(move |commands, hit| {
+        if !hit.inside {
+            return;
+        }
+        commands.spawn((
+            PbrBundle {
+                mesh: mesh.clone(),
+                material: material.clone(),
+                transform: Transform::from_translation(hit.world + hit.normal * DROP_HEIGHT),
+                ..default()
+            },
+            RigidBody::Dynamic,
+            Collider::ball(BALL_RADIUS),
+            // so the balls are cleared with the Delete key
+            HitMarker,
+        ));
+    }));
+}
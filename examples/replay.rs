@@ -0,0 +1,86 @@
+//! Record clicks to a file, then replay them to check they pick the same.
+//!
+//! `cargo run --example replay --features serde` records: click the planes, then press Enter to
+//! save the session to `session.ron`. `cargo run --example replay --features serde -- replay`
+//! loads it, re-picks every click against the same scene and exits, panicking if any hit differs.
+
+use bevy::{app::AppExit, core::FrameCount, prelude::*};
+use bevy_intersect_plane::{
+    IntersectPlanePlugin, MainCamera, MyPlane, PickRecorder, PickSession, PlanePicker,
+};
+
+const SESSION_PATH: &str = "session.ron";
+
+fn main() {
+    let replay = std::env::args().nth(1).as_deref() == Some("replay");
+    let mut app = App::new();
+    app.add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+        .add_systems(Startup, setup);
+    if replay {
+        // once the camera's transform has been propagated
+        app.add_systems(
+            Update,
+            check_replay.run_if(|frames: Res<FrameCount>| frames.0 == 2),
+        );
+    } else {
+        app.init_resource::<PickRecorder>()
+            .add_systems(Update, save_session);
+    }
+    app.run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // the scene has to be spawned the same way for both, so the entities match
+    let plane = MyPlane::new(2.0);
+    let plane_mesh = meshes.add(plane.to_plane().into());
+    for (x, y) in [(-1.2, 0.0), (1.2, 0.0), (0.0, 1.0)] {
+        commands.spawn((
+            plane,
+            PbrBundle {
+                mesh: plane_mesh.clone(),
+                material: materials.add(Color::rgb(0.4, 0.5, 0.6).into()),
+                transform: Transform::from_xyz(x, y, 0.0),
+                ..default()
+            },
+        ));
+    }
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-2.0, 4.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        MainCamera,
+    ));
+}
+
+fn save_session(keys: Res<Input<KeyCode>>, recorder: Res<PickRecorder>) {
+    if !keys.just_pressed(KeyCode::Return) {
+        return;
+    }
+    let ron = recorder.to_ron().expect("sessions serialize");
+    std::fs::write(SESSION_PATH, ron).expect("couldn't write the session");
+    info!("saved {} clicks to {SESSION_PATH}", recorder.picks.len());
+}
+
+fn check_replay(picker: PlanePicker, mut exit: EventWriter<AppExit>) {
+    let ron = std::fs::read_to_string(SESSION_PATH).expect("record a session first");
+    let session = PickSession::from_ron(&ron).expect("couldn't read the session");
+    assert_eq!(session.replay(&picker), session.picks);
+    info!("all {} clicks picked the same", session.picks.len());
+    exit.send(AppExit);
+}
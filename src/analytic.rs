@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+
+use crate::{is_ray_parallel, Face, Modifiers, PlaneHit};
+
+/// Infinite planes, each given by a point on it and its normal, that are picked alongside the
+/// [`MyPlane`](crate::MyPlane) entities without needing a mesh, e.g. a conceptual ground at
+/// `y = 0`:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::AnalyticPlanes;
+/// let ground = AnalyticPlanes(vec![(Vec3::ZERO, Vec3::Y)]);
+/// ```
+///
+/// Hits on these planes have [`Entity::PLACEHOLDER`] as their entity and are always `inside`.
+/// Their `uv` (and `coords`, whatever the coordinate space) is the hit's offset from the plane's
+/// point along the hit's `tangent` and `bitangent`, in world units.
+#[derive(Resource, Clone, Debug, Default, Deref, DerefMut)]
+pub struct AnalyticPlanes(pub Vec<(Vec3, Vec3)>);
+
+impl PlaneHit {
+    /// Intersect `ray` with the infinite plane through `origin` with `normal`, building the
+    /// tangent frame around `reference_up` as in [`PlaneHit::with_reference_up`].
+    pub fn compute_analytic(
+        ray: Ray,
+        camera: Entity,
+        origin: Vec3,
+        normal: Vec3,
+        reference_up: Vec3,
+    ) -> Option<Self> {
+        let normal = normal.normalize();
+        if is_ray_parallel(&ray, normal, f32::EPSILON) {
+            return None;
+        }
+        let distance = ray.intersect_plane(origin, normal)?;
+        let world = ray.get_point(distance);
+        let (face, sign) = if ray.direction.dot(normal) > 0.0 {
+            (Face::Back, -1.0)
+        } else {
+            (Face::Front, 1.0)
+        };
+        let hit = Self {
+            entity: Entity::PLACEHOLDER,
+            camera,
+            world,
+            camera_space_point: None,
+            reference_point: None,
+            // filled in below, once there's a tangent frame
+            local_mesh_point: Vec3::ZERO,
+            axis_distances: Vec2::ZERO,
+            uv: Vec2::ZERO,
+            coords: Vec2::ZERO,
+            inside: true,
+            distance,
+            unit_scale: 1.0,
+            priority: 0,
+            face,
+            normal: normal * sign,
+            // the local Y axis is the front face's normal, whichever face was hit
+            local_normal: Vec3::Y,
+            // replaced by `with_reference_up`
+            tangent: Vec3::ZERO,
+            bitangent: Vec3::ZERO,
+            sampled_color: None,
+            sub_frame: 1.0,
+            modifiers: Modifiers::default(),
+            cursor_ndc: None,
+            marker_rotation: None,
+            ellipse: None,
+        }
+        .with_reference_up(reference_up);
+        let offset = world - origin;
+        let uv = Vec2::new(offset.dot(hit.tangent), offset.dot(hit.bitangent));
+        Some(Self {
+            local_mesh_point: Vec3::new(uv.x, offset.dot(normal), uv.y),
+            axis_distances: uv,
+            uv,
+            coords: uv,
+            ..hit
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_normal_is_the_front_normal_from_either_side() {
+        let normal = Vec3::new(1.0, 1.0, 0.0).normalize();
+        for (origin, face) in [(normal * 5.0, Face::Front), (normal * -5.0, Face::Back)] {
+            let ray = Ray {
+                origin,
+                direction: -origin.normalize(),
+            };
+            let hit =
+                PlaneHit::compute_analytic(ray, Entity::PLACEHOLDER, Vec3::ZERO, normal, Vec3::Y)
+                    .unwrap();
+            assert_eq!(hit.face, face);
+            assert_eq!(hit.local_normal, Vec3::Y);
+            assert!(hit.normal.abs_diff_eq(-ray.direction, 1e-5));
+            // on the plane, so no height along the front normal
+            assert!(hit.local_mesh_point.y.abs() < 1e-5);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Decides which hits on a plane are inside it, for hit regions that aren't the whole square,
+/// e.g. following a texture's alpha mask.
+///
+/// The predicate gets the hit's uv, from (0, 0) to (1, 1) across the plane, and replaces the usual
+/// bounds test: hits it returns `true` for are inside. It also sees hits beyond the plane, with uvs
+/// outside that range, so return `false` for those unless you want the region to extend past the
+/// plane.
+///
+/// It's called for every hit on the plane, every frame the plane is hovered or picked, so keep it
+/// cheap: sample a precomputed mask rather than decoding an image.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{CustomBounds, MyPlane};
+/// fn spawn(mut commands: Commands) {
+///     // only the circle inscribed in the plane counts
+///     commands.spawn((
+///         MyPlane::new(2.0),
+///         CustomBounds::new(|uv| uv.distance(Vec2::splat(0.5)) <= 0.5),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct CustomBounds(pub Box<BoundsPredicate>);
+
+/// The signature of a [`CustomBounds`] predicate.
+pub type BoundsPredicate = dyn Fn(Vec2) -> bool + Send + Sync;
+
+impl CustomBounds {
+    pub fn new(predicate: impl Fn(Vec2) -> bool + Send + Sync + 'static) -> Self {
+        Self(Box::new(predicate))
+    }
+}
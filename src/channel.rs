@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+use crate::{PlaneHit, PlaneHitEvent};
+
+/// Insert this to also send every hit a click picks down a channel, alongside its
+/// [`PlaneHitEvent`](crate::PlaneHitEvent), for consumers outside the Bevy schedule, e.g. a
+/// thread logging interactions to a database. Needs the `channel` feature.
+///
+/// Hits are sent without blocking, so a bounded channel that's full, or one whose receiver is
+/// gone, drops them.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{HitChannel, IntersectPlanePlugin, PlaneHit};
+/// let (sender, receiver) = crossbeam_channel::unbounded::<PlaneHit>();
+/// std::thread::spawn(move || {
+///     for hit in receiver {
+///         println!("{:?} at {}", hit.entity, hit.world);
+///     }
+/// });
+/// App::new()
+///     .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+///     .insert_resource(HitChannel(sender));
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct HitChannel(pub crossbeam_channel::Sender<PlaneHit>);
+
+pub(crate) fn forward_hits(channel: Res<HitChannel>, mut hits: EventReader<PlaneHitEvent>) {
+    for PlaneHitEvent { hit, .. } in hits.iter() {
+        // dropped if the channel is full or closed, as documented
+        let _ = channel.0.try_send(*hit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{
+        click_at, events, headless_app, spawn_camera, spawn_plane, window_center,
+    };
+
+    #[test]
+    fn clicked_hits_go_down_the_channel() {
+        let mut app = headless_app();
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        app.insert_resource(HitChannel(sender));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        app.update();
+        let center = window_center(&mut app);
+        click_at(&mut app, center);
+
+        let sent: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| event.hit.entity)
+            .collect();
+        let received: Vec<_> = receiver.try_iter().map(|hit| hit.entity).collect();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(received, sent);
+    }
+
+    #[test]
+    fn a_full_channel_drops_hits() {
+        let mut app = headless_app();
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        app.insert_resource(HitChannel(sender));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        app.update();
+        let center = window_center(&mut app);
+        click_at(&mut app, center);
+
+        assert_eq!(receiver.try_iter().count(), 1);
+    }
+}
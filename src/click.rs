@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use bevy::{ecs::system::SystemParam, prelude::*, utils::HashMap};
+
+use crate::{PlaneHit, PlaneHitEvent};
+
+/// Runs a closure whenever the plane it's on is clicked, i.e. gets an inside hit.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{MyPlane, OnPlaneClick};
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn((
+///         MyPlane::new(2.0),
+///         OnPlaneClick::new(|hit, commands| {
+///             commands.entity(hit.entity).despawn_recursive();
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct OnPlaneClick(pub Box<PlaneClickCallback>);
+
+/// The signature of an [`OnPlaneClick`] callback.
+pub type PlaneClickCallback = dyn Fn(&PlaneHit, &mut Commands) + Send + Sync;
+
+impl OnPlaneClick {
+    pub fn new(callback: impl Fn(&PlaneHit, &mut Commands) + Send + Sync + 'static) -> Self {
+        Self(Box::new(callback))
+    }
+}
+
+/// Debounces clicks on the plane it's on: after the plane gets a [`PlaneHitEvent`], no more are
+/// sent for it until this long has passed, e.g. for a button that shouldn't fire on every one of
+/// a burst of clicks. Hover isn't affected.
+#[derive(Component, Clone, Copy, Debug, Deref, DerefMut)]
+pub struct ClickCooldown(pub Duration);
+
+/// When planes with a [`ClickCooldown`] were last clicked.
+#[derive(SystemParam)]
+pub(crate) struct ClickCooldowns<'w, 's> {
+    q_cooldown: Query<'w, 's, &'static ClickCooldown>,
+    time: Res<'w, Time>,
+    last_click: Local<'s, HashMap<Entity, Duration>>,
+}
+
+impl ClickCooldowns<'_, '_> {
+    /// Whether a click on `entity` gets through now, starting its cooldown if it does.
+    pub(crate) fn allow(&mut self, entity: Entity) -> bool {
+        let Ok(cooldown) = self.q_cooldown.get(entity) else {
+            return true;
+        };
+        let now = self.time.elapsed();
+        if let Some(&last) = self.last_click.get(&entity) {
+            if now.saturating_sub(last) < cooldown.0 {
+                return false;
+            }
+        }
+        self.last_click.insert(entity, now);
+        true
+    }
+}
+
+pub(crate) fn dispatch_clicks(
+    mut commands: Commands,
+    mut hits: EventReader<PlaneHitEvent>,
+    q_callback: Query<&OnPlaneClick>,
+) {
+    for PlaneHitEvent { hit, .. } in hits.iter().filter(|event| event.hit.inside) {
+        if let Ok(callback) = q_callback.get(hit.entity) {
+            (callback.0)(hit, &mut commands);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{
+        test_util::{click_at, headless_app, screen_point, spawn_camera, spawn_plane},
+        HoveredPlane,
+    };
+
+    #[test]
+    fn cooldown_suppresses_quick_clicks() {
+        let mut app = headless_app();
+        // each update takes a second
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let button = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.world
+            .entity_mut(button)
+            .insert(ClickCooldown(Duration::from_millis(1500)));
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+
+        // a click, one a second later, and one two seconds after the first
+        for expected in [1, 0, 1] {
+            app.world.resource_mut::<Events<PlaneHitEvent>>().clear();
+            click_at(&mut app, center);
+            let events = app.world.resource::<Events<PlaneHitEvent>>();
+            assert_eq!(events.len(), expected);
+            // the hover carries on regardless
+            assert_eq!(**app.world.resource::<HoveredPlane>(), Some(button));
+        }
+    }
+}
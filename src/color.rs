@@ -0,0 +1,51 @@
+use bevy::{prelude::*, render::render_resource::TextureFormat};
+
+use crate::uv_to_pixel;
+
+/// The color `material` shows at `uv`: its base color, tinted by the texel of its base color
+/// texture under `uv` if it has one that's loaded and 8-bit RGBA. Other textures are skipped, so
+/// this falls back to the base color.
+pub(crate) fn material_color(
+    material: &StandardMaterial,
+    images: Option<&Assets<Image>>,
+    uv: Vec2,
+) -> Color {
+    let texel = material
+        .base_color_texture
+        .as_ref()
+        .zip(images)
+        .and_then(|(handle, images)| images.get(handle))
+        .and_then(|image| texel(image, uv));
+    match texel {
+        // the texture is multiplied in linear space, as the shader does
+        Some(texel) => {
+            let linear = Vec4::from(material.base_color.as_linear_rgba_f32())
+                * Vec4::from(texel.as_linear_rgba_f32());
+            Color::rgba_linear(linear.x, linear.y, linear.z, linear.w).as_rgba()
+        }
+        None => material.base_color,
+    }
+}
+
+/// The texel of `image` under `uv`, clamped to its edges, with (0, 0) at its top left.
+fn texel(image: &Image, uv: Vec2) -> Option<Color> {
+    let size = image.texture_descriptor.size;
+    if size.width == 0 || size.height == 0 {
+        return None;
+    }
+    let pixel = uv_to_pixel(uv, UVec2::new(size.width, size.height));
+    let start = (pixel.y * size.width + pixel.x) as usize * 4;
+    let [r, g, b, a] = *image.data.get(start..start + 4)? else {
+        return None;
+    };
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8UnormSrgb => Some(Color::rgba_u8(r, g, b, a)),
+        TextureFormat::Rgba8Unorm => Some(Color::rgba_linear(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )),
+        _ => None,
+    }
+}
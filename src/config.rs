@@ -0,0 +1,535 @@
+use std::{marker::PhantomData, sync::atomic::AtomicBool};
+
+use bevy::prelude::*;
+
+use crate::{MainCamera, MyPlane};
+
+/// Which of the planes under the cursor are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PickMode {
+    /// Report every plane the cursor ray crosses, in front-to-back order, whether or not the hit
+    /// lies within the plane bounds
+    #[default]
+    All,
+    /// Report only the nearest plane whose bounds contain the hit. Among planes with a
+    /// [`PickPriority`](crate::PickPriority), the highest priority wins however far away it is.
+    Closest,
+}
+
+/// The coordinate space [`PlaneHit::coords`](crate::PlaneHit::coords) is reported in.
+///
+/// Whichever is chosen, [`PlaneHit::uv`](crate::PlaneHit::uv) stays in `ZeroToOne`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CoordSpace {
+    /// From (-0.5, -0.5) to (0.5, 0.5) across the plane, with (0, 0) at its center
+    Centered,
+    /// From (0, 0) to (1, 1) across the plane
+    #[default]
+    ZeroToOne,
+    /// In world units from the plane's center, along its horizontal and vertical axes
+    World,
+    /// In texels of a texture of `size` stretched across the plane, from (0, 0) to `size`
+    Texels { size: UVec2 },
+}
+
+impl CoordSpace {
+    /// Convert `uv`, from (0, 0) to (1, 1) across a plane of world `size`, into this space.
+    pub fn convert_uv(self, uv: Vec2, size: Vec2) -> Vec2 {
+        match self {
+            Self::Centered => uv - 0.5,
+            Self::ZeroToOne => uv,
+            Self::World => (uv - 0.5) * size,
+            Self::Texels { size: texels } => uv * texels.as_vec2(),
+        }
+    }
+}
+
+/// The corner of a plane [`PlaneHit::uv`](crate::PlaneHit::uv) is (0, 0) at, see
+/// [`PickSettings::uv_origin`]. Named as seen on a texture drawn on a `shape::Plane`, whose top
+/// edge is at local -Z and left edge at local -X. Uvs increase away from the corner either way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Corner {
+    /// The -X, -Z corner, as in the uvs of `shape::Plane` and of most texture conventions
+    #[default]
+    TopLeft,
+    /// The +X, -Z corner
+    TopRight,
+    /// The -X, +Z corner, e.g. for OpenGL-style uvs with V up
+    BottomLeft,
+    /// The +X, +Z corner
+    BottomRight,
+}
+
+impl Corner {
+    /// Remap `uv`, which is (0, 0) at [`Corner::TopLeft`], to be (0, 0) at this corner.
+    pub fn from_top_left(self, uv: Vec2) -> Vec2 {
+        match self {
+            Self::TopLeft => uv,
+            Self::TopRight => Vec2::new(1.0 - uv.x, uv.y),
+            Self::BottomLeft => Vec2::new(uv.x, 1.0 - uv.y),
+            Self::BottomRight => 1.0 - uv,
+        }
+    }
+}
+
+/// How planes hit at exactly the same distance, e.g. coplanar planes, are ordered.
+///
+/// Whichever rule is chosen, remaining ties go to the lowest [`Entity::index`], so the result
+/// never depends on query order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The lowest [`Entity::index`] wins
+    #[default]
+    EntityIndex,
+    /// The plane drawn on top wins, i.e. the one whose [`StandardMaterial::depth_bias`] is
+    /// highest
+    RenderOrder,
+    /// The highest [`PickPriority`](crate::PickPriority) wins
+    Priority,
+}
+
+/// Which hit [`PickMode::Closest`] picks, among the highest [`PickPriority`](crate::PickPriority)
+/// inside hits.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SelectBy {
+    /// The one nearest the camera along the ray, weighted by
+    /// [`PickSettings::facing_weight`]
+    #[default]
+    RayDistance,
+    /// The one nearest this world point, e.g. the player character for picking the nearest of
+    /// several stacked floors
+    DistanceToPoint(Vec3),
+}
+
+/// Where picking rays start, which is what [`PlaneHit::distance`](crate::PlaneHit::distance) is
+/// measured from, see [`PickSettings::ray_origin`].
+///
+/// Whichever is chosen, rays keep the direction from the camera through the cursor. From a
+/// [`RayOrigin::Point`] or [`RayOrigin::Entity`] away from the camera the ray runs parallel to
+/// the cursor's, so hits shift by the offset between the two, as if the camera sat at the pivot.
+/// To aim from a pivot at whatever is under the cursor instead, pick the cursor ray from the
+/// camera, then [`PlanePicker::pick_from_world_ray`](crate::PlanePicker::pick_from_world_ray)
+/// from the pivot towards the hit.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RayOrigin {
+    /// On the camera's near clip plane, where [`Camera::viewport_to_world`] starts rays, so
+    /// distances are from what's first visible
+    #[default]
+    NearPlane,
+    /// Level with the camera's position: at the camera itself for a perspective camera, or on the
+    /// plane through it facing the view for an orthographic one, so distances are from the
+    /// camera, e.g. to compare with `Transform::translation` distances
+    CameraOrigin,
+    /// At this world point, e.g. a third-person character's aim point
+    Point(Vec3),
+    /// At this entity's [`GlobalTransform`], e.g. a third-person camera's pivot, following it as
+    /// it moves. Nothing is picked while the entity has no `GlobalTransform`.
+    Entity(Entity),
+}
+
+/// The units of [`PickSettings::pick_tolerance`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToleranceMode {
+    /// World units, so the tolerance looks smaller the further away a plane is
+    #[default]
+    WorldUnits,
+    /// Viewport pixels, so the tolerance looks the same at any distance. It's converted to world
+    /// units at each hit, through the camera's projection.
+    Pixels,
+}
+
+/// Restricts picking to planes facing roughly one way, see [`PickSettings::desired_normal`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DesiredNormal {
+    pub direction: Vec3,
+    /// The largest angle, in radians, a plane's normal may make with `direction`
+    pub tolerance: f32,
+}
+
+impl DesiredNormal {
+    pub fn new(direction: Vec3, tolerance: f32) -> Self {
+        Self {
+            direction,
+            tolerance,
+        }
+    }
+
+    /// Whether a plane with `normal` is picked. Which way the normal points along its line
+    /// doesn't matter, so either side of a plane is accepted.
+    pub fn accepts(&self, normal: Vec3) -> bool {
+        let cos = self
+            .direction
+            .normalize_or_zero()
+            .dot(normal.normalize_or_zero())
+            .abs();
+        cos >= self.tolerance.cos()
+    }
+}
+
+/// Settings for how planes are picked, see [`PickingConfig`].
+#[derive(Clone, Debug)]
+pub struct PickSettings {
+    /// The mouse button that triggers a pick
+    pub pick_button: MouseButton,
+    /// How far, in logical pixels, a touch can move between touching down and lifting off and
+    /// still count as a tap, which picks like a click where it's lifted, for everything clicks
+    /// reach. Touches that move further are only drags, like holding the pick button, see
+    /// [`PlaneDragEvent`](crate::PlaneDragEvent), so a swipe across the scene doesn't click what
+    /// it ends on. Touches are read from the primary window.
+    pub tap_threshold_px: f32,
+    /// Skip planes whose [`StandardMaterial`] base color alpha is below this, e.g. `Some(0.01)`
+    /// for fully transparent overlays that can't be seen. Planes without a material, or whose
+    /// material hasn't loaded, are still picked. `None`, the default, picks planes whatever
+    /// their alpha. Analytic planes have no material, so they're always picked.
+    pub min_alpha: Option<f32>,
+    /// Pin the hit of each press into [`PinnedPick`](crate::PinnedPick) until this key confirms
+    /// it, for two-step placement. `None`, the default, pins nothing. Clicks still send their
+    /// usual events.
+    pub pin_confirm_key: Option<KeyCode>,
+    pub mode: PickMode,
+    /// Ignore hits on the back of a plane, i.e. where the ray travels along the plane normal.
+    ///
+    /// Like rendering, this spares planes whose [`StandardMaterial`] has no `cull_mode`, so
+    /// they're picked from both sides, as they're drawn. (Its `double_sided` only changes the
+    /// lighting of the back.) Planes whose material isn't loaded are culled.
+    pub cull_backfaces: bool,
+    /// Flip U of hits on the back of a plane, so a double-sided texture reads the right way round
+    /// from behind. [`PlaneHit::face`](crate::PlaneHit::face) still tells you which side was hit.
+    /// Analytic planes have no texture to mirror, so their hits are never flipped.
+    pub mirror_back_uv: bool,
+    /// Report hits that fall outside the plane bounds. When `false`, such hits send no
+    /// [`PlaneHitEvent`](crate::PlaneHitEvent) and so spawn no marker.
+    pub emit_outside_hits: bool,
+    /// Skip planes entirely outside the camera's view, using the [`Aabb`] and [`Frustum`] Bevy
+    /// computes for rendering. Planes without them (e.g. not yet rendered) are always tested, as
+    /// are all planes for a camera without a [`Frustum`]. Bevy only keeps the frustum up to date
+    /// for the projections it knows about, so with a custom projection either keep it updated
+    /// yourself or turn this off.
+    ///
+    /// Off by default: a culled plane reports no hits at all, not even the hits outside its
+    /// bounds that [`PickMode::All`] reports with [`PickSettings::emit_outside_hits`].
+    ///
+    /// [`Aabb`]: bevy::render::primitives::Aabb
+    /// [`Frustum`]: bevy::render::primitives::Frustum
+    pub frustum_culling: bool,
+    /// Skip planes that look smaller than this many logical pixels on screen, going by the
+    /// longer side of their [`plane_screen_rect`](crate::plane_screen_rect), e.g. to leave tiny
+    /// far-away planes out of [`PickMode::All`]. A skipped plane reports no hits at all, not even
+    /// outside its bounds. Planes entirely behind the camera are always tested. `None`, the
+    /// default, tests planes of any size.
+    pub min_screen_size_px: Option<f32>,
+    /// The window whose cursor is picked with, for tools with several windows. The camera has to
+    /// render to it. `None` uses the primary window.
+    ///
+    /// Window entities only exist once the app runs, so set this on the
+    /// [`PickingConfig`] resource rather than the plugin.
+    pub window: Option<Entity>,
+    /// Only pick while the cursor is inside this rectangle of the viewport, in logical pixels
+    /// from its top left, e.g. to ignore clicks on UI panels around a 3d view. `None`, the
+    /// default, picks anywhere in the viewport.
+    pub pick_region: Option<Rect>,
+    /// Where picking rays start, and so what hit distances are measured from. The camera's near
+    /// plane and origin differ by its near clip distance along the view direction, which for an
+    /// orthographic camera may be large or negative.
+    pub ray_origin: RayOrigin,
+    /// While the cursor is locked ([`CursorGrabMode::Locked`]), e.g. in a first-person game, pick
+    /// through the middle of the viewport, like a crosshair. The window doesn't update the cursor
+    /// position while it's locked, so with this off nothing is picked then.
+    ///
+    /// [`CursorGrabMode::Locked`]: bevy::window::CursorGrabMode::Locked
+    pub locked_cursor_center: bool,
+    /// Before the exact test, skip planes whose oriented bounding box the ray misses. This is
+    /// cheaper than the full projection for planes the ray clearly misses, but means only hits
+    /// within the plane bounds are reported, so [`PickMode::All`] no longer reports outside hits.
+    pub obb_broadphase: bool,
+    /// Also count hits up to this far outside a plane's edges as inside, so planes are easier to
+    /// hit. 0 uses the exact bounds. [`PickSettings::obb_broadphase`] skips planes before the
+    /// tolerance is applied, so it only has an effect with the broadphase off.
+    pub pick_tolerance: f32,
+    /// The units of `pick_tolerance`
+    pub tolerance_mode: ToleranceMode,
+    /// Move each plane's edges this far inwards, in world units, so only hits at least this far
+    /// from an edge are inside, e.g. so neighbouring tiles in a grid editor don't fight over
+    /// their shared edge. Negative values move the edges outwards instead. 0 uses the exact
+    /// bounds.
+    ///
+    /// Applied before `pick_tolerance`, which still extends a shrunk plane. Like
+    /// `pick_tolerance`, a negative margin only has an effect with
+    /// [`PickSettings::obb_broadphase`] off.
+    pub shrink_margin: f32,
+    /// Merge hits whose world points are within this distance of each other into one, e.g. a
+    /// click on the shared edge of two tiles, which is inside both. The hit kept is the one
+    /// inside a plane, then the one with the highest [`PickPriority`](crate::PickPriority), then
+    /// the one on the plane with the lowest [`Entity::index`]. `None`, the default, reports
+    /// every hit.
+    pub seam_epsilon: Option<f32>,
+    /// A forgiving pick for users who find a single pixel too precise: when the cursor ray isn't
+    /// inside any plane, the plane whose edge passes closest to the cursor, within this many
+    /// viewport pixels, counts as hit, at the nearest point of its edge. 0 turns this off.
+    ///
+    /// This goes by a plane's rectangle, ignoring any [`CustomBounds`](crate::CustomBounds), and
+    /// like `pick_tolerance` only has an effect with [`PickSettings::obb_broadphase`] off.
+    pub cursor_radius: f32,
+    /// Also count rays passing within this distance above or below a plane, inside its bounds, as
+    /// hitting it, for surfaces drawn a little off the plane, e.g. by a displacement or parallax
+    /// material. Such hits are inside, and reported where the ray enters that band rather than
+    /// where it crosses the plane. 0 tests the exact plane.
+    pub thickness: f32,
+    /// Ignore hits further than this distance along the ray
+    pub max_distance: Option<f32>,
+    /// In [`PickMode::All`], report only this many of the nearest hits, e.g. the top 3 layers
+    /// under the cursor. Outside hits count towards it too. `None`, the default, reports them all.
+    pub max_hits: Option<usize>,
+    /// Ignore hits nearer than the camera's near clip plane or beyond its far clip plane, which
+    /// wouldn't be drawn. Only works with the built-in perspective and orthographic projections.
+    pub respect_clip_planes: bool,
+    /// Only pick planes whose normal lies within the tolerance of this direction, e.g.
+    /// horizontal for a tool that places things on walls only. `None` picks planes facing any way.
+    pub desired_normal: Option<DesiredNormal>,
+    /// Only planes whose [`PickFilter`](crate::PickFilter) shares a bit with this mask are
+    /// tested. Planes without a filter are always tested.
+    pub accept_mask: u32,
+    /// The direction the tangent frame of each hit is built around, see
+    /// [`PlaneHit::with_reference_up`](crate::PlaneHit::with_reference_up)
+    pub reference_up: Vec3,
+    /// Also report each hit relative to the camera, in
+    /// [`PlaneHit::camera_space_point`](crate::PlaneHit::camera_space_point). Off by default to
+    /// save the matrix inverse.
+    pub camera_space_points: bool,
+    /// Also report each hit relative to this entity's [`GlobalTransform`], in
+    /// [`PlaneHit::reference_point`](crate::PlaneHit::reference_point), e.g. on the deck of a
+    /// moving ship, for a position that stays put as the ship moves. Nothing is reported while
+    /// the entity has no `GlobalTransform`.
+    ///
+    /// Like [`PickSettings::window`], set this on the [`PickingConfig`] resource rather than the
+    /// plugin.
+    pub reference_frame: Option<Entity>,
+    /// The coordinate space hits report [`PlaneHit::coords`](crate::PlaneHit::coords) in
+    pub coord_space: CoordSpace,
+    /// The corner of each plane hits' uvs start from. The default, [`Corner::TopLeft`], is the
+    /// orientation of `shape::Plane`'s own uvs. It's applied before any
+    /// [`UvTransform`](crate::UvTransform), and so also orients `coords`.
+    pub uv_origin: Corner,
+    /// Mirror V, after [`PickSettings::uv_origin`], e.g. to match a texture loaded upside down.
+    /// With the default origin that's the same as [`Corner::BottomLeft`]. Off by default.
+    pub flip_v: bool,
+    /// How many display units there are to a world unit, e.g. 100 to show measurements in
+    /// centimeters in a scene laid out in meters. Hits keep their world positions and distances
+    /// and also report them scaled, see [`PlaneHit::scaled_world`](crate::PlaneHit::scaled_world).
+    pub unit_scale: f32,
+    /// Report the color of each clicked plane's [`StandardMaterial`] at the hit, in
+    /// [`PlaneHit::sampled_color`](crate::PlaneHit::sampled_color), e.g. for an eyedropper. Off
+    /// by default to save the texture lookups.
+    pub sample_color: bool,
+    /// Which hit counts as the closest, see [`SelectBy`]
+    pub select_by: SelectBy,
+    /// How equidistant hits are ordered
+    pub tie_break: TieBreak,
+    /// In [`PickMode::Closest`], favour planes facing the ray over planes seen edge-on, which
+    /// otherwise make picks jitter between overlapping angled planes. A plane seen exactly
+    /// edge-on counts as `1 + facing_weight` times as far away as it is, one facing the ray
+    /// head-on at its actual distance. 0 picks by distance alone.
+    pub facing_weight: f32,
+    /// In [`PickMode::All`], also fold up to this many of the nearest inside hits into a single
+    /// [`BlendedHitEvent`](crate::BlendedHitEvent), weighting each plane by its material alpha as
+    /// if the planes were composited front to back. `None` disables blending.
+    pub blend_layers: Option<usize>,
+    /// Also send a [`PlacementEvent`](crate::PlacementEvent) for each click, averaging the inside
+    /// hits up to this far behind the nearest one into a single point to place an object at.
+    /// Works in any [`PickMode`]. `None` sends none.
+    pub placement_band: Option<f32>,
+    /// How much of the previous frame's smoothed [`HoverUv`](crate::HoverUv) is kept each frame,
+    /// from 0 (no smoothing) towards 1 (follows the cursor ever more slowly).
+    pub hover_smoothing: f32,
+    /// Send a [`BlendHoverEvent`](crate::BlendHoverEvent) while the hovered point is within this
+    /// distance, in world units, of an edge the hovered plane shares with another plane, with
+    /// a weight for blending the two. `None`, the default, sends none.
+    pub blend_seam_distance: Option<f32>,
+    /// For scenes with so many planes that testing them all every frame is too slow: spread the
+    /// hover pick over several frames, testing at most this many planes per frame. `None` tests
+    /// every plane every frame.
+    ///
+    /// Each pass follows the cursor ray from the frame it started, and the hover only updates
+    /// when a pass completes, so with `n` planes [`HoveredPlane`](crate::HoveredPlane) and
+    /// friends lag up to `n / budget` frames behind the cursor. Planes spawned or despawned
+    /// during a pass may be missed or tested twice until the next one. Clicks still test every
+    /// plane.
+    pub budget_per_frame: Option<usize>,
+    /// A safety valve for runaway scenes, e.g. planes spawned by mistake every frame: while there
+    /// are more pickable planes than this, picks test none of them and hit nothing, and a warning
+    /// is logged each time a plugin instance goes over it. Analytic planes don't count. `None`,
+    /// the default, has no limit.
+    pub max_planes_per_pick: Option<usize>,
+    /// Only update the hover on frames where
+    /// [`cursor_or_camera_changed`](crate::cursor_or_camera_changed) or
+    /// [`planes_changed`](crate::planes_changed), saving the work in idle scenes.
+    /// [`PickSettings::budget_per_frame`] passes pause in between. Clicks are
+    /// always picked.
+    pub skip_idle_hover: bool,
+    /// Leave the plane being dragged out of the hover while [`PickSettings::pick_button`] is held,
+    /// so [`HoveredPlane`](crate::HoveredPlane) and friends report the plane beneath it, e.g. the
+    /// drop target in drag and drop. The dragged plane is the
+    /// [`AnchorPick::anchor`](crate::AnchorPick::anchor), and hovers again once it's released.
+    /// Clicks and drag events still pick it.
+    pub hover_ignores_dragged: bool,
+    /// Let a released drag coast on, sending a [`PlaneCoastEvent`](crate::PlaneCoastEvent) each
+    /// frame, slowing by this much: the velocity decays by `exp(-drag_friction)` every second, so
+    /// higher values stop sooner. It starts from the drag's last
+    /// [`uv_velocity`](crate::PlaneDragEvent::uv_velocity). `None`, the default, stops dead.
+    pub drag_friction: Option<f32>,
+    /// Split the cursor's movement since the last hover update into this many steps and hover the
+    /// first plane crossed along the way, so a fast flick across a thin plane still hovers it for
+    /// a frame. `0` and `1` only test where the cursor is now. Not applied with
+    /// [`PickSettings::budget_per_frame`].
+    pub hover_substeps: u32,
+    /// Log every click pick through `tracing`, with the plane entity, uv and whether the hit was
+    /// inside as fields: hits inside the plane bounds at info level, others at debug level.
+    pub log_picks: bool,
+}
+
+impl Default for PickSettings {
+    fn default() -> Self {
+        Self {
+            pick_button: MouseButton::Left,
+            tap_threshold_px: 10.0,
+            min_alpha: None,
+            pin_confirm_key: None,
+            mode: PickMode::default(),
+            cull_backfaces: false,
+            mirror_back_uv: false,
+            emit_outside_hits: true,
+            frustum_culling: false,
+            min_screen_size_px: None,
+            window: None,
+            pick_region: None,
+            ray_origin: RayOrigin::default(),
+            locked_cursor_center: true,
+            obb_broadphase: false,
+            pick_tolerance: 0.0,
+            tolerance_mode: ToleranceMode::default(),
+            shrink_margin: 0.0,
+            seam_epsilon: None,
+            cursor_radius: 0.0,
+            thickness: 0.0,
+            max_distance: None,
+            max_hits: None,
+            respect_clip_planes: false,
+            desired_normal: None,
+            accept_mask: u32::MAX,
+            reference_up: Vec3::Y,
+            camera_space_points: false,
+            reference_frame: None,
+            coord_space: CoordSpace::default(),
+            uv_origin: Corner::default(),
+            flip_v: false,
+            unit_scale: 1.0,
+            sample_color: false,
+            select_by: SelectBy::default(),
+            tie_break: TieBreak::default(),
+            facing_weight: 0.0,
+            blend_layers: None,
+            placement_band: None,
+            hover_smoothing: 0.0,
+            blend_seam_distance: None,
+            budget_per_frame: None,
+            max_planes_per_pick: None,
+            skip_idle_hover: false,
+            hover_ignores_dragged: false,
+            drag_friction: None,
+            hover_substeps: 0,
+            log_picks: true,
+        }
+    }
+}
+
+/// The [`PickSettings`] used by the [`IntersectPlanePlugin`](crate::IntersectPlanePlugin)
+/// instance that picks planes marked `P` through cameras marked `C`.
+///
+/// Derefs to the settings, so `ResMut<PickingConfig>` can be used to change the settings of the
+/// default instance at runtime.
+#[derive(Resource, Deref, DerefMut)]
+pub struct PickingConfig<C = MainCamera, P = MyPlane> {
+    #[deref]
+    pub settings: PickSettings,
+    // whether this instance has warned about going over `max_planes_per_pick` since it was last
+    // under it. Picks only take `&self`, hence the atomic
+    pub(crate) warned_plane_limit: AtomicBool,
+    marker: PhantomData<fn() -> (C, P)>,
+}
+
+impl<C, P> PickingConfig<C, P> {
+    pub fn new(settings: PickSettings) -> Self {
+        Self {
+            settings,
+            warned_plane_limit: AtomicBool::new(false),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C, P> Default for PickingConfig<C, P> {
+    fn default() -> Self {
+        Self::new(PickSettings::default())
+    }
+}
+
+impl<C, P> Clone for PickingConfig<C, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.settings.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_uv_into_each_space() {
+        let uv = Vec2::new(0.25, 1.0);
+        let size = Vec2::splat(4.0);
+        assert_eq!(
+            CoordSpace::Centered.convert_uv(uv, size),
+            Vec2::new(-0.25, 0.5)
+        );
+        assert_eq!(CoordSpace::ZeroToOne.convert_uv(uv, size), uv);
+        assert_eq!(CoordSpace::World.convert_uv(uv, size), Vec2::new(-1.0, 2.0));
+        let texels = CoordSpace::Texels {
+            size: UVec2::new(64, 32),
+        };
+        assert_eq!(texels.convert_uv(uv, size), Vec2::new(16.0, 32.0));
+    }
+
+    #[test]
+    fn convert_uv_center() {
+        let size = Vec2::splat(4.0);
+        assert_eq!(
+            CoordSpace::Centered.convert_uv(Vec2::splat(0.5), size),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            CoordSpace::World.convert_uv(Vec2::splat(0.5), size),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn from_top_left_to_each_corner() {
+        let uv = Vec2::new(0.25, 0.125);
+        assert_eq!(Corner::TopLeft.from_top_left(uv), uv);
+        assert_eq!(Corner::TopRight.from_top_left(uv), Vec2::new(0.75, 0.125));
+        assert_eq!(Corner::BottomLeft.from_top_left(uv), Vec2::new(0.25, 0.875));
+        assert_eq!(
+            Corner::BottomRight.from_top_left(uv),
+            Vec2::new(0.75, 0.875)
+        );
+        // each corner is (0, 0) from its own corner
+        for (corner, top_left_uv) in [
+            (Corner::TopLeft, Vec2::ZERO),
+            (Corner::TopRight, Vec2::X),
+            (Corner::BottomLeft, Vec2::Y),
+            (Corner::BottomRight, Vec2::ONE),
+        ] {
+            assert_eq!(corner.from_top_left(top_left_uv), Vec2::ZERO);
+        }
+    }
+}
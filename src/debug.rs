@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "debug")]
+use crate::{is_ray_parallel, PlanePicker};
+use crate::{HoveredPlane, MyPlane};
+
+/// Draws the outline and normal of every pickable plane with gizmos while `enabled`.
+///
+/// Useful for understanding why picks land where they do. The plane under the cursor is drawn in
+/// `hovered_color`.
+#[derive(Resource, Clone, Debug)]
+pub struct DebugOverlay {
+    pub enabled: bool,
+    pub color: Color,
+    pub hovered_color: Color,
+    /// Length of the line drawn along each plane's normal
+    pub normal_length: f32,
+    /// Also mark where the cursor ray crosses the nearest plane, ignoring the plane bounds and
+    /// extending the ray behind the camera, and log at debug level when that changes. Handy for
+    /// finding out why a plane isn't picked, e.g. because it's behind the camera. Needs the
+    /// `debug` feature.
+    #[cfg(feature = "debug")]
+    pub show_raw_nearest: bool,
+    /// Pressing this key logs everything about the cursor ray at info level: where it starts and
+    /// points, every plane's distance along it and whether it's inside, and the closest hit. For
+    /// working out why a particular pick misbehaves, or including in a bug report. Needs the
+    /// `debug` feature.
+    #[cfg(feature = "debug")]
+    pub inspect_key: Option<KeyCode>,
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            color: Color::WHITE,
+            hovered_color: Color::YELLOW,
+            normal_length: 0.5,
+            #[cfg(feature = "debug")]
+            show_raw_nearest: false,
+            #[cfg(feature = "debug")]
+            inspect_key: None,
+        }
+    }
+}
+
+pub(crate) fn debug_overlay_enabled(overlay: Res<DebugOverlay>) -> bool {
+    overlay.enabled
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn raw_nearest_enabled(overlay: Res<DebugOverlay>) -> bool {
+    overlay.enabled && overlay.show_raw_nearest
+}
+
+pub(crate) fn draw_debug_overlay<C: Component, P: Component>(
+    overlay: Res<DebugOverlay>,
+    hovered: Res<HoveredPlane<C, P>>,
+    q_plane: Query<(Entity, &GlobalTransform, &MyPlane), With<P>>,
+    mut gizmos: Gizmos,
+) {
+    let hovered = hovered.0;
+
+    for (entity, transform, plane) in q_plane.iter() {
+        let color = if Some(entity) == hovered {
+            overlay.hovered_color
+        } else {
+            overlay.color
+        };
+        let corners = plane.corners(transform);
+        gizmos.linestrip(corners.into_iter().chain([corners[0]]), color);
+        gizmos.ray(
+            transform.translation(),
+            plane.front_normal(&transform.compute_transform()) * overlay.normal_length,
+            color,
+        );
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn draw_raw_nearest<C: Component, P: Component>(
+    picker: PlanePicker<C, P>,
+    q_plane: Query<(Entity, &GlobalTransform, &MyPlane), With<P>>,
+    mut previous: Local<Option<(Entity, bool, bool)>>,
+    mut gizmos: Gizmos,
+) {
+    let Some(ray) = picker.cursor_ray() else {
+        return;
+    };
+    let nearest = q_plane
+        .iter()
+        .filter_map(|(entity, transform, plane)| {
+            let transform = plane.oriented(&transform.compute_transform());
+            Some((entity, transform, plane, raw_distance(ray, &transform)?))
+        })
+        .min_by(|(.., a), (.., b)| a.abs().total_cmp(&b.abs()));
+    let Some((entity, transform, plane, distance)) = nearest else {
+        *previous = None;
+        return;
+    };
+
+    let point = ray.origin + ray.direction * distance;
+    let behind = distance < 0.0;
+    let local = MyPlane::to_local(&transform, point);
+    let inside = local.x.abs() <= plane.size * 0.5 && local.z.abs() <= plane.size * 0.5;
+    gizmos.line(ray.origin, point, Color::FUCHSIA);
+    gizmos.sphere(point, Quat::IDENTITY, 0.05, Color::FUCHSIA);
+
+    if *previous != Some((entity, behind, inside)) {
+        debug!(
+            "nearest plane to the cursor ray is {entity:?}, {distance:.2} along it ({side} the \
+             camera, {bounds} its bounds)",
+            side = if behind { "behind" } else { "in front of" },
+            bounds = if inside { "within" } else { "outside" },
+        );
+        *previous = Some((entity, behind, inside));
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn inspect_ray<C: Component, P: Component>(
+    overlay: Res<DebugOverlay>,
+    keys: Res<Input<KeyCode>>,
+    picker: PlanePicker<C, P>,
+    q_plane: Query<(Entity, &GlobalTransform, &MyPlane), With<P>>,
+) {
+    if !overlay
+        .inspect_key
+        .is_some_and(|key| keys.just_pressed(key))
+    {
+        return;
+    }
+    let Some(ray) = picker.cursor_ray() else {
+        info!("inspect ray: no cursor ray, the cursor isn't over the camera's window");
+        return;
+    };
+    info!(
+        "inspect ray: from {origin:.3} towards {direction:.3}",
+        origin = ray.origin,
+        direction = ray.direction
+    );
+
+    let hits = picker.pick_all();
+    for (entity, transform, plane) in q_plane.iter() {
+        if let Some(hit) = hits.iter().find(|hit| hit.entity == entity) {
+            info!(
+                "  {entity:?}: {distance:.3} along the ray, {bounds} its bounds, {face:?} face, \
+                 uv {uv:.3}",
+                distance = hit.distance,
+                bounds = if hit.inside { "within" } else { "outside" },
+                face = hit.face,
+                uv = hit.uv,
+            );
+        } else if let Some(distance) =
+            raw_distance(ray, &plane.oriented(&transform.compute_transform()))
+        {
+            info!(
+                "  {entity:?}: {distance:.3} along the ray, not picked: behind the camera or left \
+                 out by the pick settings"
+            );
+        } else {
+            info!("  {entity:?}: parallel to the ray");
+        }
+    }
+    match picker.pick_closest() {
+        Some(hit) => info!("  closest: {:?} at uv {:.3}", hit.entity, hit.uv),
+        None => info!("  closest: nothing"),
+    }
+}
+
+/// The signed distance along `ray` to the infinite plane, negative if the plane is behind the ray
+/// origin. `None` if the ray runs parallel to the plane. `transform` is the plane's
+/// [oriented](MyPlane::oriented) transform, so the normal follows its mesh.
+#[cfg(feature = "debug")]
+fn raw_distance(ray: Ray, transform: &Transform) -> Option<f32> {
+    let (_, normal, _) = MyPlane::axes(transform);
+    if is_ray_parallel(&ray, normal, f32::EPSILON) {
+        return None;
+    }
+    Some((transform.translation - ray.origin).dot(normal) / ray.direction.dot(normal))
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::PlaneHit;
+
+/// How to lay a decal, a flat quad such as a bullet hole or a footprint, onto the surface at a
+/// hit. Build its entity with [`Decal::bundle`].
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{Decal, PlaneHitEvent};
+/// fn spawn_decals(
+///     mut commands: Commands,
+///     mut hits: EventReader<PlaneHitEvent>,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     mut materials: ResMut<Assets<StandardMaterial>>,
+/// ) {
+///     let mesh = meshes.add(Decal::mesh());
+///     let material = materials.add(Color::BLACK.into());
+///     for PlaneHitEvent { hit, .. } in hits.iter().filter(|event| event.hit.inside) {
+///         commands.spawn(Decal::default().bundle(hit, mesh.clone(), material.clone()));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Decal {
+    /// The width and height of the quad
+    pub size: f32,
+    /// How far to turn the quad about the normal, in radians, counterclockwise looking at the
+    /// surface
+    pub rotation: f32,
+    /// How far to lift the quad off the surface along the normal, so they don't z-fight
+    pub offset: f32,
+}
+
+impl Default for Decal {
+    fn default() -> Self {
+        Self {
+            size: 0.2,
+            rotation: 0.0,
+            offset: 0.001,
+        }
+    }
+}
+
+impl Decal {
+    /// The quad to draw a decal with, one unit across and facing +Y. Add it to your assets once
+    /// and share it between decals, which are scaled to size.
+    pub fn mesh() -> Mesh {
+        shape::Plane::from_size(1.0).into()
+    }
+
+    /// Where the decal goes for `hit`: on the surface, lined up with the hit's tangent frame (see
+    /// [`PlaneHit::spawn_transform`]) then turned by `rotation`, and scaled to `size`.
+    pub fn transform(&self, hit: &PlaneHit) -> Transform {
+        let mut transform = hit.spawn_transform(self.offset);
+        transform.rotate_local_y(self.rotation);
+        transform.scale = Vec3::new(self.size, 1.0, self.size);
+        transform
+    }
+
+    /// The decal for `hit`, drawn with `mesh` (usually [`Decal::mesh`]) and `material`.
+    pub fn bundle(
+        &self,
+        hit: &PlaneHit,
+        mesh: Handle<Mesh>,
+        material: Handle<StandardMaterial>,
+    ) -> PbrBundle {
+        PbrBundle {
+            mesh,
+            material,
+            transform: self.transform(hit),
+            ..default()
+        }
+    }
+}
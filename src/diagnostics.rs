@@ -0,0 +1,105 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+#[cfg(feature = "diagnostics")]
+use bevy::diagnostic::{Diagnostic, DiagnosticId, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+/// The picking work done so far this frame, added to by every pick through a
+/// [`PlanePicker`](crate::PlanePicker). Picks only take `&self`, hence the atomics.
+///
+/// Only inserted with the `diagnostics` feature, and taken each frame by
+/// `record_diagnostics`.
+#[derive(Resource, Default)]
+#[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+pub(crate) struct PickCounters {
+    picks: AtomicUsize,
+    tested: AtomicUsize,
+    hits: AtomicUsize,
+    nanos: AtomicU64,
+}
+
+#[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+impl PickCounters {
+    /// Count a pick that tested `tested` planes and found `hits` hits in `time`.
+    pub(crate) fn add(&self, tested: usize, hits: usize, time: std::time::Duration) {
+        self.picks.fetch_add(1, Ordering::Relaxed);
+        self.tested.fetch_add(tested, Ordering::Relaxed);
+        self.hits.fetch_add(hits, Ordering::Relaxed);
+        self.nanos
+            .fetch_add(time.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+/// The [`Diagnostic`]s picking reports each frame with the `diagnostics` feature, for
+/// `LogDiagnosticsPlugin` and other diagnostics overlays. They cover every pick of the frame,
+/// hover and clicks, through every plugin instance and [`PlanePicker`](crate::PlanePicker).
+#[cfg(feature = "diagnostics")]
+pub struct PickDiagnostics;
+
+#[cfg(feature = "diagnostics")]
+impl PickDiagnostics {
+    /// How many planes each pick tested, on average
+    pub const PLANES_TESTED: DiagnosticId =
+        DiagnosticId::from_u128(0x38202927dff14d459693adc8fa764d77);
+    /// How many hits all the picks found
+    pub const HITS: DiagnosticId = DiagnosticId::from_u128(0x8effcd7cbff640f3aad2008b613136cd);
+    /// How long all the picks took, in milliseconds
+    pub const PICK_TIME: DiagnosticId = DiagnosticId::from_u128(0x5a2a6df912684d30aa2156a8653b6891);
+
+    pub(crate) fn register(app: &mut App) {
+        app.init_resource::<PickCounters>()
+            .register_diagnostic(Diagnostic::new(
+                Self::PLANES_TESTED,
+                "planes_tested_per_pick",
+                20,
+            ))
+            .register_diagnostic(Diagnostic::new(Self::HITS, "pick_hits", 20))
+            .register_diagnostic(
+                Diagnostic::new(Self::PICK_TIME, "pick_time", 20).with_suffix("ms"),
+            )
+            .add_systems(Last, record_diagnostics);
+    }
+}
+
+/// Report this frame's [`PickCounters`] and start them again from zero.
+#[cfg(feature = "diagnostics")]
+fn record_diagnostics(mut diagnostics: Diagnostics, counters: Res<PickCounters>) {
+    let picks = counters.picks.swap(0, Ordering::Relaxed);
+    let tested = counters.tested.swap(0, Ordering::Relaxed);
+    let hits = counters.hits.swap(0, Ordering::Relaxed);
+    let nanos = counters.nanos.swap(0, Ordering::Relaxed);
+    if picks > 0 {
+        diagnostics.add_measurement(PickDiagnostics::PLANES_TESTED, || {
+            tested as f64 / picks as f64
+        });
+    }
+    diagnostics.add_measurement(PickDiagnostics::HITS, || hits as f64);
+    diagnostics.add_measurement(PickDiagnostics::PICK_TIME, || nanos as f64 / 1e6);
+}
+
+#[cfg(all(test, feature = "diagnostics"))]
+mod tests {
+    use bevy::diagnostic::DiagnosticsStore;
+
+    use super::*;
+    use crate::test_util::{headless_app, move_cursor, spawn_camera, spawn_plane, window_center};
+
+    #[test]
+    fn hover_is_reported_each_frame() {
+        let mut app = headless_app();
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        app.update();
+        let center = window_center(&mut app);
+        move_cursor(&mut app, Some(center));
+        app.update();
+
+        let store = app.world.resource::<DiagnosticsStore>();
+        let value = |id| store.get(id).and_then(Diagnostic::value);
+        // the one hover pick, through both planes
+        assert_eq!(value(PickDiagnostics::PLANES_TESTED), Some(2.0));
+        assert_eq!(value(PickDiagnostics::HITS), Some(2.0));
+        assert!(value(PickDiagnostics::PICK_TIME).is_some_and(|ms| ms >= 0.0));
+    }
+}
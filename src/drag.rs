@@ -0,0 +1,388 @@
+use std::marker::PhantomData;
+
+use bevy::{ecs::system::SystemParam, prelude::*, utils::HashMap};
+
+use crate::{picking::ClickInput, plane_delta, MainCamera, MyPlane, PlaneHit, PlanePicker};
+
+/// Sent every frame of a drag that the dragged plane is under the cursor. A drag starts by
+/// pressing [`PickSettings::pick_button`](crate::PickSettings::pick_button) over a plane, or
+/// touching it, and lasts as long as it's held. A touch drag follows the touch rather than the
+/// cursor.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct PlaneDragEvent {
+    /// The dragged plane
+    pub entity: Entity,
+    /// The uv under the cursor, which may be outside the plane bounds
+    pub uv: Vec2,
+    /// How fast `uv` is changing, per second. Zero on the first frame of a drag, and whenever the
+    /// plane comes back under the cursor after leaving it.
+    pub uv_velocity: Vec2,
+}
+
+/// Sent during a drag whenever the cursor crosses the bounds of the dragged plane, see
+/// [`PlaneDragEvent`].
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundaryCrossEvent {
+    /// The dragged plane
+    pub entity: Entity,
+    /// Whether the cursor moved into the plane bounds, rather than out of them
+    pub entered: bool,
+}
+
+/// Sent every frame after a drag is released while the dragged point coasts on across the plane,
+/// for "throw" interactions, with
+/// [`PickSettings::drag_friction`](crate::PickSettings::drag_friction) set. It starts from the
+/// last [`PlaneDragEvent`] and slows down until it stops.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct PlaneCoastEvent {
+    /// The plane that was dragged
+    pub entity: Entity,
+    /// Where the coasting point is, which may go outside the plane bounds
+    pub uv: Vec2,
+    /// How fast `uv` is changing, per second
+    pub uv_velocity: Vec2,
+}
+
+/// Below this speed, in uv per second, a coasting drag stops.
+const COAST_STOP_SPEED: f32 = 0.01;
+
+/// The plane pressed on with [`PickSettings::pick_button`](crate::PickSettings::pick_button),
+/// and how far the cursor has moved across it since, for "grab and move relative" interactions,
+/// for the same instance as [`HoveredPlane`](crate::HoveredPlane).
+///
+/// A touch anchors the same way, following the touch. Cleared when the button or touch is
+/// released.
+#[derive(Resource)]
+pub struct AnchorPick<C = MainCamera, P = MyPlane> {
+    /// The hit the press landed on, if it landed on a plane
+    pub anchor: Option<PlaneHit>,
+    /// How far the point under the cursor has moved across the anchor's plane since the press,
+    /// along the plane's horizontal and vertical axes in world units, as in [`plane_delta`].
+    /// Zero on the press itself, and when the cursor ray misses the plane.
+    pub offset: Vec2,
+    /// The cursor ray at the press
+    ray: Option<Ray>,
+    marker: PhantomData<fn() -> (C, P)>,
+}
+
+impl<C, P> Default for AnchorPick<C, P> {
+    fn default() -> Self {
+        Self {
+            anchor: None,
+            offset: Vec2::ZERO,
+            ray: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn track_anchor<C: Component, P: Component>(
+    picker: PlanePicker<C, P>,
+    input: ClickInput,
+    q_transform: Query<(&GlobalTransform, Option<&MyPlane>)>,
+    mut anchor: ResMut<AnchorPick<C, P>>,
+) {
+    if let Some(pointer) = input.press(picker.config()) {
+        let ray = picker.pointer_ray(pointer);
+        *anchor = AnchorPick {
+            anchor: ray.and_then(|ray| picker.pick_closest_ray(ray)),
+            ray,
+            ..default()
+        };
+        return;
+    }
+    let Some(pointer) = input.held(picker.config()) else {
+        if anchor.anchor.is_some() {
+            *anchor = AnchorPick::default();
+        }
+        return;
+    };
+    let (Some(hit), Some(start)) = (anchor.anchor, anchor.ray) else {
+        return;
+    };
+    // measured across the plane where it is now, so the anchor moves with the plane
+    let offset = picker
+        .pointer_ray(pointer)
+        .zip(q_transform.get(hit.entity).ok())
+        .and_then(|(ray, (transform, plane))| {
+            // other surfaces are measured across the plane of their transform
+            let plane = plane.copied().unwrap_or_else(|| MyPlane::new(0.0));
+            plane_delta(&start, &ray, &transform.compute_transform(), &plane)
+        })
+        .unwrap_or(Vec2::ZERO);
+    if anchor.offset != offset {
+        anchor.offset = offset;
+    }
+}
+
+/// Where the cursor was on a dragged plane last frame.
+#[derive(Clone, Copy)]
+pub(crate) struct DragState {
+    inside: bool,
+    uv: Option<Vec2>,
+    uv_velocity: Vec2,
+}
+
+/// The events a drag sends.
+#[derive(SystemParam)]
+pub(crate) struct DragEvents<'w> {
+    drag: EventWriter<'w, PlaneDragEvent>,
+    cross: EventWriter<'w, BoundaryCrossEvent>,
+    coast: EventWriter<'w, PlaneCoastEvent>,
+}
+
+pub(crate) fn track_drags<C: Component, P: Component>(
+    picker: PlanePicker<C, P>,
+    input: ClickInput,
+    time: Res<Time>,
+    mut dragged: Local<HashMap<Entity, DragState>>,
+    mut coasting: Local<Vec<PlaneCoastEvent>>,
+    mut events: DragEvents,
+) {
+    let delta = time.delta_seconds();
+    if let Some(friction) = picker.config().drag_friction {
+        let decay = (-friction * delta).exp();
+        for coast in coasting.iter_mut() {
+            coast.uv_velocity *= decay;
+            coast.uv += coast.uv_velocity * delta;
+            events.coast.send(*coast);
+        }
+    }
+    coasting.retain(|coast| coast.uv_velocity.length() >= COAST_STOP_SPEED);
+
+    if let Some(pointer) = input.press(picker.config()) {
+        dragged.clear();
+        // grabbing again catches anything still coasting
+        coasting.clear();
+        let ray = picker.pointer_ray(pointer);
+        if let Some(hit) = ray.and_then(|ray| picker.pick_closest_ray(ray)) {
+            dragged.insert(
+                hit.entity,
+                DragState {
+                    inside: true,
+                    uv: Some(hit.uv),
+                    uv_velocity: Vec2::ZERO,
+                },
+            );
+            events.drag.send(PlaneDragEvent {
+                entity: hit.entity,
+                uv: hit.uv,
+                uv_velocity: Vec2::ZERO,
+            });
+        }
+        return;
+    }
+    let Some(pointer) = input.held(picker.config()) else {
+        if picker.config().drag_friction.is_some() {
+            coasting.extend(dragged.drain().filter_map(|(entity, state)| {
+                Some(PlaneCoastEvent {
+                    entity,
+                    uv: state.uv?,
+                    uv_velocity: state.uv_velocity,
+                })
+                .filter(|coast| coast.uv_velocity.length() >= COAST_STOP_SPEED)
+            }));
+        }
+        dragged.clear();
+        return;
+    };
+    if dragged.is_empty() {
+        return;
+    }
+
+    let hits = picker
+        .pointer_ray(pointer)
+        .map(|ray| picker.pick_ray(ray))
+        .unwrap_or_default();
+    for (&entity, state) in dragged.iter_mut() {
+        // a plane that isn't hit at all, e.g. because the cursor left the window, counts as outside
+        let hit = hits.iter().find(|hit| hit.entity == entity);
+        let inside = hit.is_some_and(|hit| hit.inside);
+        if inside != state.inside {
+            events.cross.send(BoundaryCrossEvent {
+                entity,
+                entered: inside,
+            });
+            state.inside = inside;
+        }
+
+        let uv = hit.map(|hit| hit.uv);
+        if let Some(uv) = uv {
+            let uv_velocity = match state.uv {
+                Some(previous) if delta > 0.0 => (uv - previous) / delta,
+                _ => Vec2::ZERO,
+            };
+            events.drag.send(PlaneDragEvent {
+                entity,
+                uv,
+                uv_velocity,
+            });
+            state.uv_velocity = uv_velocity;
+        }
+        state.uv = uv;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{ecs::event::ManualEventReader, input::ButtonState, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::{
+        test_util::{
+            headless_app, move_cursor, screen_point, send_button, spawn_camera, spawn_plane,
+        },
+        PickingConfig,
+    };
+
+    /// An app looking down on a plane of side 2 at the origin, and its camera and plane.
+    fn drag_app() -> (App, Entity, Entity) {
+        let mut app = headless_app();
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let plane = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        (app, camera, plane)
+    }
+
+    /// Press the pick button over `point` on the ground, and update.
+    fn press_at(app: &mut App, camera: Entity, point: Vec3) {
+        let position = screen_point(app, camera, point);
+        move_cursor(app, Some(position));
+        send_button(app, MouseButton::Left, ButtonState::Pressed);
+        app.update();
+    }
+
+    /// Move the held cursor over `point` on the ground, and update.
+    fn drag_to(app: &mut App, camera: Entity, point: Vec3) {
+        let position = screen_point(app, camera, point);
+        move_cursor(app, Some(position));
+        app.update();
+    }
+
+    /// The events of type `E` sent since `reader` last read them.
+    fn read<E: Event + Clone>(app: &App, reader: &mut ManualEventReader<E>) -> Vec<E> {
+        reader
+            .iter(app.world.resource::<Events<E>>())
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn crossing_an_edge_mid_drag() {
+        let (mut app, camera, plane) = drag_app();
+        let mut reader = ManualEventReader::<BoundaryCrossEvent>::default();
+        press_at(&mut app, camera, Vec3::ZERO);
+        drag_to(&mut app, camera, Vec3::new(0.5, 0.0, 0.0));
+        assert!(read(&app, &mut reader).is_empty());
+
+        // out over the +X edge, and on further out without crossing again
+        drag_to(&mut app, camera, Vec3::new(1.5, 0.0, 0.0));
+        let left = BoundaryCrossEvent {
+            entity: plane,
+            entered: false,
+        };
+        assert_eq!(read(&app, &mut reader), [left]);
+        drag_to(&mut app, camera, Vec3::new(2.0, 0.0, 0.0));
+        assert!(read(&app, &mut reader).is_empty());
+
+        // and back in
+        drag_to(&mut app, camera, Vec3::new(0.5, 0.0, 0.0));
+        let entered = BoundaryCrossEvent {
+            entered: true,
+            ..left
+        };
+        assert_eq!(read(&app, &mut reader), [entered]);
+    }
+
+    #[test]
+    fn a_steady_drag_has_a_steady_velocity() {
+        let (mut app, camera, _) = drag_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.update();
+        let mut reader = ManualEventReader::<PlaneDragEvent>::default();
+        press_at(&mut app, camera, Vec3::new(-0.8, 0.0, 0.0));
+        read(&app, &mut reader);
+
+        // 0.1 world units on a plane of side 2 every 100ms is 0.5 uv per second
+        for step in 1..=8 {
+            drag_to(
+                &mut app,
+                camera,
+                Vec3::new(-0.8 + 0.1 * step as f32, 0.0, 0.0),
+            );
+            let [drag] = read(&app, &mut reader)[..] else {
+                panic!("one drag event per frame");
+            };
+            let expected = Vec2::new(0.5, 0.0);
+            assert!(
+                drag.uv_velocity.abs_diff_eq(expected, 1e-2),
+                "step {step}: {}",
+                drag.uv_velocity
+            );
+        }
+    }
+
+    #[test]
+    fn anchors_measure_the_drag_from_the_press() {
+        let (mut app, camera, plane) = drag_app();
+        press_at(&mut app, camera, Vec3::new(-0.5, 0.0, 0.0));
+        let anchor = app.world.resource::<AnchorPick>();
+        assert_eq!(anchor.anchor.map(|hit| hit.entity), Some(plane));
+        assert_eq!(anchor.offset, Vec2::ZERO);
+
+        drag_to(&mut app, camera, Vec3::new(0.0, 0.0, 0.25));
+        drag_to(&mut app, camera, Vec3::new(0.5, 0.0, 0.25));
+        let offset = app.world.resource::<AnchorPick>().offset;
+        assert!(offset.abs_diff_eq(Vec2::new(1.0, 0.25), 1e-3), "{offset}");
+        // past the edge, the plane carries on
+        drag_to(&mut app, camera, Vec3::new(1.5, 0.0, 0.0));
+        let offset = app.world.resource::<AnchorPick>().offset;
+        assert!(offset.abs_diff_eq(Vec2::new(2.0, 0.0), 1e-3), "{offset}");
+
+        send_button(&mut app, MouseButton::Left, ButtonState::Released);
+        app.update();
+        assert!(app.world.resource::<AnchorPick>().anchor.is_none());
+    }
+
+    #[test]
+    fn flicks_coast_to_a_stop() {
+        let (mut app, camera, plane) = drag_app();
+        app.world.resource_mut::<PickingConfig>().drag_friction = Some(2.0);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.update();
+        let mut reader = ManualEventReader::<PlaneCoastEvent>::default();
+        // a tenth of the plane in a tenth of a second
+        press_at(&mut app, camera, Vec3::new(-0.8, 0.0, 0.0));
+        drag_to(&mut app, camera, Vec3::new(-0.6, 0.0, 0.0));
+        send_button(&mut app, MouseButton::Left, ButtonState::Released);
+        app.update();
+
+        let mut coasts = Vec::new();
+        for _ in 0..50 {
+            app.update();
+            coasts.extend(read(&app, &mut reader));
+        }
+        assert!(coasts.len() > 10, "{} frames of coasting", coasts.len());
+        assert!(coasts.len() < 50, "still coasting");
+        assert!(coasts.iter().all(|coast| coast.entity == plane));
+        for pair in coasts.windows(2) {
+            assert!(pair[1].uv_velocity.x < pair[0].uv_velocity.x);
+            assert!(pair[1].uv.x > pair[0].uv.x);
+        }
+        // released at u = 0.2, and each frame keeps exp(-0.2) of the speed, so the steps add up
+        // to a geometric series
+        let decay = (-0.2f32).exp();
+        let limit = 0.2 + 0.1 * decay / (1.0 - decay);
+        let last = coasts.last().unwrap().uv.x;
+        assert!(
+            (last - limit).abs() < 0.01,
+            "stopped at {last}, not {limit}"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+
+use crate::{CustomBounds, MyPlane};
+
+/// An elliptical platform: only hits within the ellipse with these radii, along the plane's
+/// horizontal and vertical axes, are inside. It's picked as the [`MyPlane`] it's inscribed in,
+/// with [`CustomBounds`] for the ellipse, so spawn all three. Its hits report where they landed
+/// in [`PlaneHit::ellipse`](crate::PlaneHit::ellipse).
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::MyEllipse;
+/// fn spawn(mut commands: Commands) {
+///     let ellipse = MyEllipse::new(Vec2::new(2.0, 1.0));
+///     commands.spawn((ellipse, ellipse.plane(), ellipse.bounds()));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MyEllipse {
+    pub radii: Vec2,
+}
+
+impl MyEllipse {
+    pub fn new(radii: Vec2) -> Self {
+        Self { radii }
+    }
+
+    /// The plane the ellipse is inscribed in: a square as wide as its major axis.
+    pub fn plane(&self) -> MyPlane {
+        MyPlane::new(self.radii.max_element() * 2.0)
+    }
+
+    /// The bounds of the ellipse, for hits on its [`MyEllipse::plane`].
+    pub fn bounds(&self) -> CustomBounds {
+        let ellipse = *self;
+        let size = self.plane().size;
+        CustomBounds::new(move |uv| ellipse.contains((uv - 0.5) * size))
+    }
+
+    /// Whether `local`, a point on the plane relative to its center along its horizontal and
+    /// vertical axes, is within the ellipse.
+    pub fn contains(&self, local: Vec2) -> bool {
+        self.normalize(local).length_squared() <= 1.0
+    }
+
+    /// `local` (see [`MyEllipse::contains`]) in units of the radii, so the ellipse becomes the
+    /// unit circle: within length 1 inside the ellipse, and exactly 1 on its edge.
+    pub fn normalize(&self, local: Vec2) -> Vec2 {
+        local / self.radii
+    }
+
+    /// The coordinates of `local_mesh_point`, a point in its plane's local space (see
+    /// [`PlaneHit::local_mesh_point`](crate::PlaneHit::local_mesh_point)), on the ellipse.
+    pub fn coords(&self, local_mesh_point: Vec3) -> EllipseCoords {
+        let local = Vec2::new(local_mesh_point.x, local_mesh_point.z);
+        EllipseCoords {
+            local,
+            normalized: self.normalize(local),
+        }
+    }
+}
+
+/// Where a hit landed on a [`MyEllipse`], see [`PlaneHit::ellipse`](crate::PlaneHit::ellipse).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EllipseCoords {
+    /// Relative to the ellipse's center along its plane's horizontal and vertical axes
+    pub local: Vec2,
+    /// In units of the radii, see [`MyEllipse::normalize`]: within length 1 inside the ellipse
+    pub normalized: Vec2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{click_at, events, headless_app, screen_point, spawn_camera, spawn_plane},
+        PlaneHitEvent,
+    };
+
+    /// The coordinates of a click on a (2, 1) ellipse at `point`, and whether it's inside.
+    fn click_ellipse(point: Vec3) -> (EllipseCoords, bool) {
+        let mut app = headless_app();
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let ellipse = MyEllipse::new(Vec2::new(2.0, 1.0));
+        let plane = spawn_plane(&mut app, ellipse.plane().size, Transform::IDENTITY);
+        app.world
+            .entity_mut(plane)
+            .insert((ellipse, ellipse.bounds()));
+        app.update();
+        let position = screen_point(&mut app, camera, point);
+        click_at(&mut app, position);
+        let hit = events::<PlaneHitEvent>(&app)[0].hit;
+        (hit.ellipse.unwrap(), hit.inside)
+    }
+
+    #[test]
+    fn major_axis_edge() {
+        let (coords, _) = click_ellipse(Vec3::new(2.0, 0.0, 0.0));
+        assert!(coords.local.abs_diff_eq(Vec2::new(2.0, 0.0), 1e-3));
+        assert!(coords.normalized.abs_diff_eq(Vec2::new(1.0, 0.0), 1e-3));
+        // just past it is outside, though still on the plane
+        let (coords, inside) = click_ellipse(Vec3::new(1.9, 0.0, 0.5));
+        assert!(coords.normalized.length() > 1.0);
+        assert!(!inside);
+    }
+
+    #[test]
+    fn minor_axis_edge() {
+        let (coords, _) = click_ellipse(Vec3::new(0.0, 0.0, -1.0));
+        assert!(coords.local.abs_diff_eq(Vec2::new(0.0, -1.0), 1e-3));
+        assert!(coords.normalized.abs_diff_eq(Vec2::new(0.0, -1.0), 1e-3));
+        // just within it is inside
+        let (coords, inside) = click_ellipse(Vec3::new(0.0, 0.0, -0.9));
+        assert!(coords.normalized.abs_diff_eq(Vec2::new(0.0, -0.9), 1e-3));
+        assert!(inside);
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+/// Partitions pickable planes into groups, e.g. by team or editing mode.
+///
+/// A plane with a `PickFilter` is only tested if its mask shares a bit with
+/// [`PickSettings::accept_mask`](crate::PickSettings::accept_mask). Planes without one are
+/// always tested.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Deref, DerefMut)]
+pub struct PickFilter(pub u32);
+
+impl PickFilter {
+    /// Whether a plane with this filter is picked under `accept_mask`.
+    pub fn accepts(self, accept_mask: u32) -> bool {
+        self.0 & accept_mask != 0
+    }
+}
+
+/// Lets a plane win the closest hit over planes in front of it, e.g. a floating UI plane over
+/// world geometry. Higher wins; planes without one have priority 0.
+///
+/// This decides [`PickMode::Closest`](crate::PickMode::Closest) picks, hover and
+/// [`PlanePicker::pick_closest`](crate::PlanePicker::pick_closest), but other hits are still
+/// reported front to back. Among planes hit at exactly the same distance, it's also the order
+/// used when [`PickSettings::tie_break`](crate::PickSettings::tie_break) is
+/// [`TieBreak::Priority`](crate::TieBreak::Priority).
+#[derive(
+    Component, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut,
+)]
+pub struct PickPriority(pub i32);
+
+/// When `Some`, only this plane is picked, by every plugin instance, for an editing mode focused on
+/// one plane. Analytic planes aren't picked either. `None`, the default, picks every plane.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct FocusPlane(pub Option<Entity>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{app_with, click_at, events, spawn_camera, spawn_plane, window_center},
+        HoveredPlane, IntersectPlanePlugin, PlaneHitEvent,
+    };
+
+    #[test]
+    fn masked_out_planes_are_never_hit() {
+        let mut app = app_with(IntersectPlanePlugin::default().accept_mask(0b01));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        // in front of the others, so it would be hit and hovered first
+        let masked = spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 2.0, 0.0));
+        app.world.entity_mut(masked).insert(PickFilter(0b10));
+        let accepted = spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        app.world.entity_mut(accepted).insert(PickFilter(0b11));
+        let unfiltered = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let center = window_center(&mut app);
+        click_at(&mut app, center);
+
+        let hits: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| event.hit.entity)
+            .collect();
+        assert_eq!(hits, [accepted, unfiltered]);
+        assert_eq!(**app.world.resource::<HoveredPlane>(), Some(accepted));
+    }
+}
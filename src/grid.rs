@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+
+use crate::{HoveredPlane, MyPlane};
+
+/// A grid of square cells laid over a plane from its (0, 0) uv corner, for snapping hits to
+/// tiles.
+///
+/// Cells are counted along the plane's horizontal and vertical axes. If the plane size isn't a
+/// multiple of `cell_size`, the last row and column of cells overhang the plane.
+///
+/// Put one on a plane to draw its grid while it's hovered, see [`GridLines`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GridSnap {
+    /// The side length of each cell in world units
+    pub cell_size: f32,
+}
+
+impl GridSnap {
+    pub fn new(cell_size: f32) -> Self {
+        Self { cell_size }
+    }
+
+    /// The cell containing `uv` on `plane`.
+    pub fn cell(&self, uv: Vec2, plane: &MyPlane) -> GridCell {
+        let cells = uv * plane.size / self.cell_size;
+        let index = cells.floor();
+        GridCell {
+            index: index.as_ivec2(),
+            cell_fraction: cells - index,
+        }
+    }
+
+    /// The uv at the center of `cell` on `plane`.
+    pub fn cell_center(&self, cell: IVec2, plane: &MyPlane) -> Vec2 {
+        (cell.as_vec2() + 0.5) * self.cell_size / plane.size
+    }
+
+    /// The cells on `plane` a brush at `uv` reaches, with how strongly each is affected, for
+    /// sculpting or painting: the cell under `uv` with weight 1, and the others whose centers are
+    /// within `radius` world units of its center, fading to 0 at `radius`.
+    ///
+    /// `falloff` is how much of the radius the fade takes, from 0 for a hard-edged brush that
+    /// weighs every cell 1, to 1 for a fade all the way from the center. Cells overhanging the
+    /// plane edge are included, cells beyond it aren't.
+    pub fn brush(&self, uv: Vec2, plane: &MyPlane, radius: f32, falloff: f32) -> Vec<(IVec2, f32)> {
+        if self.cell_size <= 0.0 {
+            return Vec::new();
+        }
+        let center = self.cell(uv, plane).index;
+        let last = IVec2::splat((plane.size / self.cell_size).ceil() as i32 - 1);
+        let reach = (radius / self.cell_size).ceil() as i32;
+        let fade = radius * falloff.clamp(0.0, 1.0);
+        let min = (center - reach).max(IVec2::ZERO);
+        let max = (center + reach).min(last);
+        let mut cells = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let cell = IVec2::new(x, y);
+                let distance = (cell - center).as_vec2().length() * self.cell_size;
+                if cell != center && distance >= radius {
+                    continue;
+                }
+                let weight = if fade > 0.0 {
+                    ((radius - distance) / fade).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                cells.push((cell, weight));
+            }
+        }
+        cells
+    }
+}
+
+/// A grid of `cells` cells across a plane, for snapping to the vertices where its lines cross
+/// rather than to cells as with [`GridSnap`]. The vertices along the plane's edges and at its
+/// corners count too, so there are `cells + 1` vertices along each axis.
+///
+/// Markers for hits on a plane with one are moved to the nearest vertex.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GridVertexSnap {
+    /// The number of cells along the plane's horizontal and vertical axes
+    pub cells: UVec2,
+}
+
+impl GridVertexSnap {
+    pub fn new(cells: UVec2) -> Self {
+        Self { cells }
+    }
+
+    /// The vertex nearest `uv`, from (0, 0) at the plane's (0, 0) uv corner to `cells` at the
+    /// opposite one. Points beyond the plane snap to the vertices on its edge.
+    pub fn vertex(&self, uv: Vec2) -> UVec2 {
+        let cells = self.cells.max(UVec2::ONE).as_vec2();
+        (uv * cells).round().clamp(Vec2::ZERO, cells).as_uvec2()
+    }
+
+    /// The uv of `vertex`.
+    pub fn vertex_uv(&self, vertex: UVec2) -> Vec2 {
+        vertex.as_vec2() / self.cells.max(UVec2::ONE).as_vec2()
+    }
+}
+
+/// Where a point lies on a [`GridSnap`] grid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridCell {
+    /// Which cell the point is in
+    pub index: IVec2,
+    /// Where in the cell the point is, from (0, 0) to (1, 1) along the same axes as uv, e.g.
+    /// for interpolating between the cell's corners. The center of a cell is (0.5, 0.5).
+    pub cell_fraction: Vec2,
+}
+
+/// Insert this to draw the grid of the hovered plane, if it has a [`GridSnap`], with gizmos.
+///
+/// Only the hovered plane's grid is drawn, so this stays cheap with many gridded planes.
+#[derive(Resource, Clone, Debug)]
+pub struct GridLines {
+    pub color: Color,
+}
+
+impl Default for GridLines {
+    fn default() -> Self {
+        Self {
+            color: Color::rgba(1.0, 1.0, 1.0, 0.5),
+        }
+    }
+}
+
+pub(crate) fn draw_hovered_grid<C: Component, P: Component>(
+    lines: Res<GridLines>,
+    hovered: Res<HoveredPlane<C, P>>,
+    q_plane: Query<(&GlobalTransform, &MyPlane, &GridSnap), With<P>>,
+    mut gizmos: Gizmos,
+) {
+    let Some((transform, plane, grid)) = hovered.and_then(|entity| q_plane.get(entity).ok()) else {
+        return;
+    };
+    if grid.cell_size <= 0.0 {
+        return;
+    }
+    let [origin, x_end, _, z_end] = plane.corners(transform);
+    let x_axis = (x_end - origin) / plane.size;
+    let z_axis = (z_end - origin) / plane.size;
+    let cells = (plane.size / grid.cell_size).ceil() as u32;
+    for i in 0..=cells {
+        // overhanging cells are cut off at the plane edge
+        let offset = (i as f32 * grid.cell_size).min(plane.size);
+        let x = origin + x_axis * offset;
+        gizmos.line(x, x + z_axis * plane.size, lines.color);
+        let z = origin + z_axis * offset;
+        gizmos.line(z, z + x_axis * plane.size, lines.color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_center_is_half_a_cell_in() {
+        let (snap, plane) = (GridSnap::new(1.0), MyPlane::new(4.0));
+        let cell = IVec2::new(2, 1);
+        let center = snap.cell_center(cell, &plane);
+        assert_eq!(
+            snap.cell(center, &plane),
+            GridCell {
+                index: cell,
+                cell_fraction: Vec2::splat(0.5),
+            }
+        );
+    }
+
+    #[test]
+    fn cell_fraction_within_a_cell() {
+        let (snap, plane) = (GridSnap::new(1.0), MyPlane::new(4.0));
+        let cell = snap.cell(Vec2::new(0.3125, 0.0625), &plane);
+        assert_eq!(cell.index, IVec2::new(1, 0));
+        assert_eq!(cell.cell_fraction, Vec2::new(0.25, 0.25));
+    }
+
+    #[test]
+    fn vertex_snap_near_a_corner() {
+        let snap = GridVertexSnap::new(UVec2::new(4, 2));
+        assert_eq!(snap.vertex(Vec2::new(0.97, 0.04)), UVec2::new(4, 0));
+        assert_eq!(snap.vertex_uv(UVec2::new(4, 0)), Vec2::new(1.0, 0.0));
+        assert_eq!(snap.vertex(Vec2::new(0.02, 0.99)), UVec2::new(0, 2));
+        // beyond the plane, onto its edge and corner vertices
+        assert_eq!(snap.vertex(Vec2::new(1.3, 0.5)), UVec2::new(4, 1));
+        assert_eq!(snap.vertex(Vec2::new(-0.2, -0.4)), UVec2::ZERO);
+        // inside, to the nearest crossing
+        assert_eq!(snap.vertex(Vec2::new(0.3, 0.7)), UVec2::new(1, 1));
+    }
+
+    #[test]
+    fn brush_fades_from_the_center() {
+        let (snap, plane) = (GridSnap::new(1.0), MyPlane::new(10.0));
+        let center = IVec2::new(5, 5);
+        let uv = snap.cell_center(center, &plane);
+        let cells = snap.brush(uv, &plane, 3.0, 1.0);
+        let weight = |cell: IVec2| cells.iter().find(|(c, _)| *c == cell).map(|(_, w)| *w);
+
+        assert_eq!(weight(center), Some(1.0));
+        // the furthest cells within the radius barely count, and the ones on it not at all
+        let edge = weight(center + IVec2::new(2, 2)).unwrap();
+        assert!(edge > 0.0 && edge < 0.1);
+        assert_eq!(weight(center + IVec2::new(3, 0)), None);
+        // and the rest fade linearly in between
+        for &(cell, w) in &cells {
+            let distance = (cell - center).as_vec2().length();
+            assert!((w - (1.0 - distance / 3.0)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn hard_brush_weighs_every_cell_fully() {
+        let (snap, plane) = (GridSnap::new(1.0), MyPlane::new(10.0));
+        let uv = snap.cell_center(IVec2::new(5, 5), &plane);
+        let cells = snap.brush(uv, &plane, 2.0, 0.0);
+        assert!(cells.iter().all(|&(_, weight)| weight == 1.0));
+        // clipped to the plane at its corner
+        let cells = snap.brush(snap.cell_center(IVec2::ZERO, &plane), &plane, 1.5, 0.0);
+        assert!(cells.iter().all(|&(cell, _)| cell.min_element() >= 0));
+        assert_eq!(cells.len(), 4);
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+/// Lifts hits on a plane off its surface by a height sampled at the hit's uv, for snapping
+/// objects to gently curved ground drawn from a flat plane, e.g. a displaced terrain mesh.
+///
+/// The sampler gets the hit's uv, from (0, 0) to (1, 1) across the plane, and returns how far to
+/// move the hit along the plane's front normal, in world units. This is an approximation: the hit
+/// is still found on the flat plane and then moved, without intersecting the ray with the curved
+/// surface, so the lifted point drifts off the cursor ray the steeper the surface and the more
+/// glancing the view. [`PlaneHit::distance`](crate::PlaneHit::distance) stays the distance to
+/// the flat plane.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{Heightfield, MyPlane};
+/// fn spawn(mut commands: Commands) {
+///     // a gentle hill in the middle of the plane
+///     commands.spawn((
+///         MyPlane::new(10.0),
+///         Heightfield::new(|uv| (1.0 - uv.distance(Vec2::splat(0.5)) * 2.0).max(0.0)),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct Heightfield(pub Box<HeightSampler>);
+
+/// The signature of a [`Heightfield`] sampler.
+pub type HeightSampler = dyn Fn(Vec2) -> f32 + Send + Sync;
+
+impl Heightfield {
+    pub fn new(sampler: impl Fn(Vec2) -> f32 + Send + Sync + 'static) -> Self {
+        Self(Box::new(sampler))
+    }
+}
@@ -0,0 +1,184 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{Face, GridSnap, HoverUv, HoveredPlane, MarkerConfig, MyPlane};
+
+/// Insert this to swap the material of the hovered plane for this one.
+///
+/// Materials are only swapped when the hovered plane changes, and by swapping handles rather
+/// than editing the material assets, so nothing is re-uploaded while the hover stays put.
+#[derive(Resource, Clone, Debug, Deref)]
+pub struct HoverHighlight(pub Handle<StandardMaterial>);
+
+/// The material a highlighted plane had before it was hovered, restored when it isn't.
+#[derive(Component, Clone, Debug, Deref)]
+pub struct UnhighlightedMaterial(pub Handle<StandardMaterial>);
+
+pub(crate) fn highlight_hovered_plane<C: Component, P: Component>(
+    mut commands: Commands,
+    highlight: Res<HoverHighlight>,
+    hovered: Res<HoveredPlane<C, P>>,
+    mut previous: Local<Option<Entity>>,
+    q_material: Query<(&Handle<StandardMaterial>, Option<&UnhighlightedMaterial>)>,
+) {
+    if *previous == hovered.0 {
+        return;
+    }
+    if let Some(entity) = previous.take() {
+        if let Ok((_, Some(original))) = q_material.get(entity) {
+            commands
+                .entity(entity)
+                .insert(original.0.clone())
+                .remove::<UnhighlightedMaterial>();
+        }
+    }
+    if let Some(entity) = hovered.0 {
+        if let Ok((material, None)) = q_material.get(entity) {
+            commands
+                .entity(entity)
+                .insert((UnhighlightedMaterial(material.clone()), highlight.0.clone()));
+        }
+    }
+    *previous = hovered.0;
+}
+
+/// Insert this to draw a quad over the grid cell under the cursor, for tile-editor-style
+/// feedback.
+///
+/// The quad is a single entity per plugin instance, moved as the cursor moves between cells and
+/// hidden when the cursor isn't over a plane. Like markers, it's lifted off the surface by
+/// [`MarkerConfig::marker_offset`] to avoid z-fighting.
+#[derive(Resource, Clone, Debug)]
+pub struct CellHighlight {
+    pub grid: GridSnap,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Tags the quad drawn by [`CellHighlight`].
+#[derive(Component)]
+pub struct CellHighlightQuad;
+
+/// The quad entity and the cell it was last placed over.
+#[derive(Default)]
+pub(crate) struct CellHighlightState {
+    quad: Option<Entity>,
+    cell: Option<(Entity, Face, IVec2)>,
+}
+
+pub(crate) fn highlight_hovered_cell<C: Component, P: Component>(
+    mut commands: Commands,
+    highlight: Res<CellHighlight>,
+    marker_config: Res<MarkerConfig>,
+    hover_uv: Res<HoverUv<C, P>>,
+    q_plane: Query<(&GlobalTransform, &MyPlane)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut state: Local<CellHighlightState>,
+) {
+    let grid = highlight.grid;
+    let quad = *state.quad.get_or_insert_with(|| {
+        commands
+            .spawn((
+                PbrBundle {
+                    mesh: meshes.add(shape::Plane::from_size(1.0).into()),
+                    material: highlight.material.clone(),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                CellHighlightQuad,
+            ))
+            .id()
+    });
+
+    // the hover's own hit, so it follows the hover settings and costs no extra pick
+    let hovered = hover_uv.hit.and_then(|hit| {
+        let (transform, plane) = q_plane.get(hit.entity).ok()?;
+        let transform = transform.compute_transform();
+        // the uv picking reports may be remapped, so go by where the hit really is
+        let uv = plane.local_uv(hit.local_mesh_point);
+        Some((hit, transform, plane, grid.cell(uv, plane).index))
+    });
+    let cell = hovered.map(|(hit, _, _, index)| (hit.entity, hit.face, index));
+    // only touch the quad when the cell under the cursor changes
+    if highlight.is_changed() || cell != state.cell {
+        match hovered {
+            Some((hit, transform, plane, index)) => {
+                let center = plane.point_at_uv(&transform, grid.cell_center(index, plane));
+                // the quad is one-sided, so turn it over to face a ray from behind the plane
+                let oriented = plane.oriented(&transform);
+                let rotation = match hit.face {
+                    Face::Front => oriented.rotation,
+                    Face::Back => oriented.rotation * Quat::from_rotation_x(PI),
+                };
+                commands.entity(quad).insert((
+                    Transform {
+                        translation: center + hit.normal * marker_config.marker_offset,
+                        rotation,
+                        // stretched along with the plane
+                        scale: oriented.scale * grid.cell_size,
+                    },
+                    highlight.material.clone(),
+                    Visibility::Inherited,
+                ));
+            }
+            None => {
+                commands.entity(quad).insert(Visibility::Hidden);
+            }
+        }
+        state.cell = cell;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{headless_app, move_cursor, spawn_camera, spawn_plane, window_center};
+
+    /// How many frames the material assets were changed on.
+    #[derive(Resource, Default)]
+    struct MaterialWrites(usize);
+
+    fn count_material_writes(
+        materials: Res<Assets<StandardMaterial>>,
+        mut writes: ResMut<MaterialWrites>,
+    ) {
+        if materials.is_changed() {
+            writes.0 += 1;
+        }
+    }
+
+    #[test]
+    fn a_still_hover_leaves_the_materials_alone() {
+        let mut app = headless_app();
+        app.init_resource::<MaterialWrites>()
+            .add_systems(Last, count_material_writes);
+        let mut materials = app.world.resource_mut::<Assets<StandardMaterial>>();
+        let original = materials.add(StandardMaterial::default());
+        let highlighted = materials.add(Color::YELLOW.into());
+        app.insert_resource(HoverHighlight(highlighted.clone()));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let plane = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.world.entity_mut(plane).insert(original.clone());
+        // the first run sees the assets added above as a change
+        app.update();
+        app.world.resource_mut::<MaterialWrites>().0 = 0;
+
+        let center = window_center(&mut app);
+        move_cursor(&mut app, Some(center));
+        for _ in 0..5 {
+            app.update();
+        }
+        // swapped over by handle, without touching either asset
+        let handle = app.world.get::<Handle<StandardMaterial>>(plane).unwrap();
+        assert_eq!(handle, &highlighted);
+        assert_eq!(app.world.resource::<MaterialWrites>().0, 0);
+
+        // and back again
+        move_cursor(&mut app, None);
+        app.update();
+        app.update();
+        let handle = app.world.get::<Handle<StandardMaterial>>(plane).unwrap();
+        assert_eq!(handle, &original);
+        assert_eq!(app.world.resource::<MaterialWrites>().0, 0);
+    }
+}
@@ -0,0 +1,623 @@
+use bevy::prelude::*;
+
+use crate::{is_ray_parallel, EllipseCoords, MyPlane, PlaneEdge};
+
+/// Where the cursor ray crossed a plane.
+#[derive(Clone, Copy, Debug)]
+pub struct PlaneHit {
+    /// The plane that was hit
+    pub entity: Entity,
+    /// The camera the ray was cast from, which identifies the plugin instance that picked it
+    pub camera: Entity,
+    /// The intersection point in world space
+    pub world: Vec3,
+    /// The intersection point relative to the camera, which looks down its -Z axis. Only filled
+    /// in when [`PickSettings::camera_space_points`](crate::PickSettings::camera_space_points) is
+    /// set.
+    pub camera_space_point: Option<Vec3>,
+    /// The intersection point relative to the
+    /// [`PickSettings::reference_frame`](crate::PickSettings::reference_frame) entity, in its
+    /// local space. Only filled in when that's set.
+    pub reference_point: Option<Vec3>,
+    /// Where the cursor was in normalized device coordinates: from -1 to 1 across the camera's
+    /// viewport, left to right and bottom to top, e.g. to feed a post-process effect. Only filled
+    /// in for picks under the cursor, a touch or a viewport position, not for rays picked
+    /// directly.
+    pub cursor_ndc: Option<Vec2>,
+    /// The rotation that lays a marker flat on the plane, see
+    /// [`MarkerConfig::flat_on_plane`](crate::MarkerConfig::flat_on_plane). Only filled in for
+    /// the hits handed to the [`MarkerSpawner`](crate::MarkerSpawner) while that's set.
+    pub marker_rotation: Option<Quat>,
+    /// The intersection point in the plane's local space (see [`MyPlane::to_local`]), in the
+    /// mesh's units rather than normalized like `uv`: from -size/2 to size/2 along X and Z when
+    /// inside, whatever the transform's scale, for logic working in the plane mesh's own
+    /// coordinates. For an analytic plane it's relative to the plane's origin, along `tangent`,
+    /// the front face's normal and `bitangent`.
+    pub local_mesh_point: Vec3,
+    /// How far the hit is from the plane's center along its horizontal and vertical axes, in world
+    /// units, e.g. for rulers or snapping differently along each axis. Unlike `uv` it isn't
+    /// divided by the plane size: it's the projection onto each axis picking measures first, so
+    /// from minus to plus half the plane's width and height, scale and all, when inside. For an
+    /// analytic plane it's along `tangent` and `bitangent`.
+    pub axis_distances: Vec2,
+    /// The intersection point on the plane surface, from (0, 0) to (1, 1) when `inside`
+    pub uv: Vec2,
+    /// Where the hit landed on the plane's [`MyEllipse`](crate::MyEllipse), for planes with
+    /// one. Straight out of [`PlaneHit::compute`] this is `None`.
+    pub ellipse: Option<EllipseCoords>,
+    /// The intersection point on the plane surface in the configured
+    /// [`PickSettings::coord_space`](crate::PickSettings::coord_space). Straight out of
+    /// [`PlaneHit::compute`] this is the same as `uv`.
+    pub coords: Vec2,
+    /// Whether the intersection lies within the finite plane mesh
+    pub inside: bool,
+    /// Distance along the ray to the intersection
+    pub distance: f32,
+    /// How many display units there are to a world unit, from
+    /// [`PickSettings::unit_scale`](crate::PickSettings::unit_scale): see
+    /// [`PlaneHit::scaled_world`] and [`PlaneHit::scaled_distance`]. Straight out of
+    /// [`PlaneHit::compute`] this is 1.
+    pub unit_scale: f32,
+    /// The plane's [`PickPriority`](crate::PickPriority), 0 if it has none. Straight out of
+    /// [`PlaneHit::compute`] this is always 0.
+    pub priority: i32,
+    /// Which side of the plane the ray struck
+    pub face: Face,
+    /// The plane normal in world space, flipped to face the ray on [`Face::Back`] hits
+    pub normal: Vec3,
+    /// The plane normal in the plane's local space, the same space as `local_mesh_point`:
+    /// always the front face's normal, whichever face was hit. For a [`MyPlane`] that's
+    /// [`MyPlane::LOCAL_NORMAL`] (negated with [`MyPlane::invert_normal`]), which the plane's
+    /// rotation, then its [`MyPlane::mesh_rotation`], turns into `normal` (negated on
+    /// [`Face::Back`] hits).
+    pub local_normal: Vec3,
+    /// Together with `normal` and `bitangent`, a right-handed frame at the hit: `tangent`,
+    /// `normal` and `bitangent` line up like X, Y and Z.
+    ///
+    /// Straight out of [`PlaneHit::compute`] this is the plane's horizontal axis (flipped along
+    /// with the normal on [`Face::Back`] hits), but the picking system rebuilds the frame around
+    /// [`PickSettings::reference_up`](crate::PickSettings::reference_up), see
+    /// [`PlaneHit::with_reference_up`].
+    pub tangent: Vec3,
+    /// See `tangent`. Straight out of [`PlaneHit::compute`] this is the plane's vertical axis.
+    pub bitangent: Vec3,
+    /// The color of the plane's [`StandardMaterial`] at the hit, including its base color
+    /// texture, when [`PickSettings::sample_color`](crate::PickSettings::sample_color) is set.
+    /// `None` for planes without a material (or whose material hasn't loaded) and for hits
+    /// outside the plane bounds. Straight out of [`PlaneHit::compute`] this is `None`.
+    pub sampled_color: Option<Color>,
+    /// When in the frame the click that made the hit happened, from 0, the start of the frame,
+    /// to 1, its end, for input replay that needs more precision than a frame. Bevy's input
+    /// events don't carry timestamps, so this is 1.0 unless the click's timing was given with
+    /// [`PressSubFrame`]. Straight out of [`PlaneHit::compute`] this is 1.0.
+    pub sub_frame: f32,
+    /// The modifier keys held when the click that made the hit happened, e.g. for shift-click
+    /// to add to a selection. Straight out of [`PlaneHit::compute`], and for hover, none are held.
+    pub modifiers: Modifiers,
+}
+
+/// Which modifier keys are held, see [`PlaneHit::modifiers`]. Either key of a pair counts, e.g.
+/// right shift as well as left.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    /// The Windows or Command key
+    pub super_key: bool,
+}
+
+impl Modifiers {
+    /// The modifiers held in `keys` right now.
+    pub fn from_keys(keys: &Input<KeyCode>) -> Self {
+        Self {
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            control: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            super_key: keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+        }
+    }
+}
+
+/// The side of a plane a ray struck.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Face {
+    /// The side the plane normal points out of
+    Front,
+    /// The other side, where the ray travels along the plane normal
+    Back,
+}
+
+/// Sent for every plane the cursor ray crosses when picking.
+#[derive(Event, Clone, Debug)]
+pub struct PlaneHitEvent {
+    pub hit: PlaneHit,
+    /// The plane's [`Name`], if it has one, e.g. to label the hit in logs or UI
+    pub name: Option<String>,
+}
+
+/// When in the frame this frame's click happened, as a fraction from 0 to 1, for tools that
+/// know it, e.g. input replay that injects a recorded press: set it alongside the press, before
+/// [`IntersectPlaneSet::Pick`](crate::IntersectPlaneSet::Pick), and the hits the click makes
+/// report it as their [`PlaneHit::sub_frame`]. It's cleared once the frame's picks are done.
+#[derive(Resource, Clone, Copy, Debug, Default, Deref, DerefMut)]
+pub struct PressSubFrame(pub Option<f32>);
+
+pub(crate) fn clear_press_sub_frame(mut sub_frame: ResMut<PressSubFrame>) {
+    if sub_frame.is_some() {
+        sub_frame.0 = None;
+    }
+}
+
+/// Sent when [`PickSettings::blend_layers`](crate::PickSettings::blend_layers) is set, folding
+/// the stacked inside hits of a pick into one result.
+#[derive(Event, Clone, Debug)]
+pub struct BlendedHitEvent {
+    /// The camera the ray was cast from
+    pub camera: Entity,
+    /// The weighted average of the hit points
+    pub world: Vec3,
+    /// The weighted average of the hit UVs
+    pub uv: Vec2,
+    /// Each blended plane and its weight, front to back. The weights sum to 1.
+    pub weights: Vec<(Entity, f32)>,
+}
+
+impl BlendedHitEvent {
+    /// Composite `layers` (hits sorted front to back, each with its alpha) front to back: each
+    /// plane contributes its alpha times whatever the planes in front of it let through.
+    ///
+    /// Returns `None` if nothing contributes, e.g. there are no layers or they're all fully
+    /// transparent.
+    pub fn from_layers(
+        camera: Entity,
+        layers: impl IntoIterator<Item = (PlaneHit, f32)>,
+    ) -> Option<Self> {
+        let mut transmittance = 1.0;
+        let mut weighted = Vec::new();
+        for (hit, alpha) in layers {
+            let alpha = alpha.clamp(0.0, 1.0);
+            weighted.push((hit, transmittance * alpha));
+            transmittance *= 1.0 - alpha;
+        }
+        let total: f32 = weighted.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut world = Vec3::ZERO;
+        let mut uv = Vec2::ZERO;
+        let mut weights = Vec::with_capacity(weighted.len());
+        for (hit, weight) in weighted {
+            let weight = weight / total;
+            world += hit.world * weight;
+            uv += hit.uv * weight;
+            weights.push((hit.entity, weight));
+        }
+        Some(Self {
+            camera,
+            world,
+            uv,
+            weights,
+        })
+    }
+}
+
+/// Sent when [`PickSettings::placement_band`](crate::PickSettings::placement_band) is set: one
+/// point to place an object at, smoothed across the planes a click lands on together, e.g. at the
+/// seams between floor tiles.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlacementEvent {
+    /// The camera the ray was cast from
+    pub camera: Entity,
+    /// The weighted average of the hit points
+    pub world: Vec3,
+    /// The weighted average of the hit normals
+    pub normal: Vec3,
+}
+
+impl PlacementEvent {
+    /// Average the inside `hits` (sorted front to back) up to `band` further along the ray than
+    /// the nearest, weighting each by how squarely the ray meets it, so glancing hits count less.
+    ///
+    /// Returns `None` if there's no inside hit, or the ray only grazes them.
+    pub fn from_hits(camera: Entity, ray: Ray, hits: &[PlaneHit], band: f32) -> Option<Self> {
+        let nearest = hits.iter().find(|hit| hit.inside)?.distance;
+        let mut total = 0.0;
+        let mut world = Vec3::ZERO;
+        let mut normal = Vec3::ZERO;
+        for hit in hits
+            .iter()
+            .filter(|hit| hit.inside && hit.distance <= nearest + band)
+        {
+            let weight = ray.direction.dot(hit.normal).abs();
+            total += weight;
+            world += hit.world * weight;
+            normal += hit.normal * weight;
+        }
+        if total <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            camera,
+            world: world / total,
+            normal: normal.normalize_or_zero(),
+        })
+    }
+}
+
+impl PlaneHit {
+    /// Rebuild the tangent frame so that the tangent is perpendicular to `reference_up`, i.e.
+    /// horizontal when `reference_up` is up, keeping the normal as it is.
+    ///
+    /// When the normal is (nearly) parallel to `reference_up` there is no such tangent, so
+    /// `Vec3::X` is used as the reference instead (or `Vec3::Z`, if `reference_up` was `Vec3::X`).
+    pub fn with_reference_up(mut self, reference_up: Vec3) -> Self {
+        const PARALLEL_EPSILON: f32 = 1e-4;
+        let tangent = [reference_up, Vec3::X, Vec3::Z]
+            .into_iter()
+            .map(|up| up.cross(self.normal))
+            .find(|tangent| tangent.length() > PARALLEL_EPSILON)
+            // `reference_up` can only be parallel to one of those, so this never happens
+            .unwrap_or(self.tangent)
+            .normalize();
+        self.tangent = tangent;
+        self.bitangent = tangent.cross(self.normal);
+        self
+    }
+
+    /// A transform at the hit, lifted `offset` along the normal, with the object's X, Y and Z
+    /// axes along `tangent`, `normal` and `bitangent`: i.e. standing up on the surface, for
+    /// spawning decals, markers or other objects at the hit.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_intersect_plane::PlaneHitEvent;
+    /// fn spawn_at_hits(mut commands: Commands, mut hits: EventReader<PlaneHitEvent>) {
+    ///     for PlaneHitEvent { hit, .. } in hits.iter() {
+    ///         commands.spawn(SpatialBundle::from_transform(hit.spawn_transform(0.01)));
+    ///     }
+    /// }
+    /// ```
+    pub fn spawn_transform(&self, offset: f32) -> Transform {
+        Transform {
+            translation: self.world + self.normal * offset,
+            rotation: Quat::from_mat3(&Mat3::from_cols(self.tangent, self.normal, self.bitangent)),
+            ..default()
+        }
+    }
+
+    /// The edge of `plane` the hit lies beyond, or `None` if it's inside. Goes by
+    /// `local_mesh_point`, so it isn't affected by how `uv` is oriented or remapped.
+    pub fn exit_edge(&self, plane: &MyPlane) -> Option<PlaneEdge> {
+        PlaneEdge::from_uv(plane.local_uv(self.local_mesh_point))
+    }
+
+    /// `world` in display units, e.g. centimeters for a `unit_scale` of 100 in a scene laid out in
+    /// meters, for showing measurements.
+    pub fn scaled_world(&self) -> Vec3 {
+        self.world * self.unit_scale
+    }
+
+    /// `distance` in display units, see [`PlaneHit::scaled_world`].
+    pub fn scaled_distance(&self) -> f32 {
+        self.distance * self.unit_scale
+    }
+
+    /// Intersect `ray` with the (infinite) plane and work out whether the hit lands on the mesh.
+    pub fn compute(
+        ray: Ray,
+        camera: Entity,
+        entity: Entity,
+        transform: &Transform,
+        plane: &MyPlane,
+    ) -> Option<Self> {
+        let transform = &plane.oriented(transform);
+        let plane_origin = transform.translation;
+        // see `MyPlane::axes` for where the normal and axes come from
+        let (x_axis, normal, z_axis) = MyPlane::axes(transform);
+        // an inverted plane's front faces the other way
+        let front_sign = if plane.invert_normal { -1.0 } else { 1.0 };
+        let plane_normal = normal * front_sign;
+        if is_ray_parallel(&ray, plane_normal, f32::EPSILON) {
+            return None;
+        }
+        let distance = ray.intersect_plane(plane_origin, plane_normal)?;
+        let world_intersection = ray.get_point(distance);
+
+        // now that we have the intersection point, we need to check that it lies within the plane mesh
+        // project onto the plane's horizontal and vertical axes, in world units
+        let local_intersection = world_intersection - plane_origin;
+        let x_projection = local_intersection.dot(x_axis);
+        let z_projection = local_intersection.dot(z_axis);
+
+        // and take it into the mesh's own space, undoing the plane's rotation and scale too
+        let local_mesh_point = MyPlane::to_local(transform, world_intersection);
+
+        // the mesh's horizontal and vertical axes are its X and Z
+        let local_intersection = Vec2::new(local_mesh_point.x, local_mesh_point.z) / plane.size;
+
+        // we know the finite plane goes from (-0.5, -0.5)*plane_size to (0.5, 0.5)*plane_size from `impl From<Plane> for Mesh`
+        // if that's not the case for your shape then adjust this "hit detection" code
+        // the other assumption is that the plane is always square, so the plane side lengths are the same
+        // which might not be true for you
+        let inside = local_intersection.x.abs() <= 0.5 && local_intersection.y.abs() <= 0.5;
+
+        // the ray travels along the normal when it strikes the back of the plane, in which case
+        // flip the normal to face the ray, and flip the tangent too to keep the frame right-handed,
+        // likewise for an inverted plane
+        let (face, sign) = if ray.direction.dot(plane_normal) > 0.0 {
+            (Face::Back, -1.0)
+        } else {
+            (Face::Front, 1.0)
+        };
+
+        // (-0.5, 0.5)..(0.5, 0.5) => (0, 1.0)..(0, 1.0)
+        let uv = local_intersection + Vec2::splat(0.5);
+
+        Some(Self {
+            entity,
+            camera,
+            world: world_intersection,
+            camera_space_point: None,
+            reference_point: None,
+            local_mesh_point,
+            axis_distances: Vec2::new(x_projection, z_projection),
+            uv,
+            coords: uv,
+            inside,
+            distance,
+            unit_scale: 1.0,
+            priority: 0,
+            face,
+            normal: plane_normal * sign,
+            local_normal: MyPlane::LOCAL_NORMAL * front_sign,
+            tangent: x_axis * sign * front_sign,
+            bitangent: z_axis,
+            sampled_color: None,
+            sub_frame: 1.0,
+            modifiers: Modifiers::default(),
+            cursor_ndc: None,
+            marker_rotation: None,
+            ellipse: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAMERA: Entity = Entity::PLACEHOLDER;
+
+    /// The hit straight down onto a plane of side 4 at height `y`, at `x`, `z`.
+    fn hit_from_above(entity: Entity, y: f32, x: f32, z: f32) -> PlaneHit {
+        let ray = Ray {
+            origin: Vec3::new(x, 10.0, z),
+            direction: Vec3::NEG_Y,
+        };
+        let transform = Transform::from_xyz(0.0, y, 0.0);
+        PlaneHit::compute(ray, CAMERA, entity, &transform, &MyPlane::new(4.0)).unwrap()
+    }
+
+    #[test]
+    fn blend_two_layers_by_alpha() {
+        let front = hit_from_above(Entity::from_raw(1), 1.0, 1.0, 0.0);
+        let back = hit_from_above(Entity::from_raw(2), 0.0, 1.0, 0.0);
+        // the front lets 3/4 through, all of which the opaque back takes
+        let blended = BlendedHitEvent::from_layers(CAMERA, [(front, 0.25), (back, 1.0)]).unwrap();
+        assert_eq!(
+            blended.weights,
+            vec![(front.entity, 0.25), (back.entity, 0.75)]
+        );
+        assert!(blended.world.abs_diff_eq(Vec3::new(1.0, 0.25, 0.0), 1e-5));
+        assert!(blended.uv.abs_diff_eq(front.uv, 1e-5));
+    }
+
+    #[test]
+    fn blend_transparent_layers_is_none() {
+        let hit = hit_from_above(Entity::from_raw(1), 0.0, 0.0, 0.0);
+        assert!(BlendedHitEvent::from_layers(CAMERA, [(hit, 0.0), (hit, 0.0)]).is_none());
+        assert!(BlendedHitEvent::from_layers(CAMERA, []).is_none());
+    }
+
+    /// Whether `tangent`, `normal` and `bitangent` line up like X, Y and Z.
+    fn is_right_handed(hit: &PlaneHit) -> bool {
+        hit.tangent
+            .cross(hit.normal)
+            .abs_diff_eq(hit.bitangent, 1e-5)
+    }
+
+    #[test]
+    fn back_face_hit_flips_the_normal() {
+        let ray = Ray {
+            origin: Vec3::new(0.5, -5.0, 0.5),
+            direction: Vec3::Y,
+        };
+        let plane = MyPlane::new(4.0);
+        let hit = PlaneHit::compute(ray, CAMERA, CAMERA, &Transform::IDENTITY, &plane).unwrap();
+        assert_eq!(hit.face, Face::Back);
+        assert_eq!(hit.normal, Vec3::NEG_Y);
+        assert!(is_right_handed(&hit));
+
+        let front = hit_from_above(CAMERA, 0.0, 0.5, 0.5);
+        assert_eq!(front.face, Face::Front);
+        assert_eq!(front.normal, Vec3::Y);
+        assert!(is_right_handed(&front));
+    }
+
+    #[test]
+    fn reference_up_along_the_normal_falls_back_to_x() {
+        let hit = hit_from_above(CAMERA, 0.0, 0.0, 0.0).with_reference_up(Vec3::Y);
+        assert!(hit.tangent.is_finite() && hit.bitangent.is_finite());
+        assert_eq!(hit.normal, Vec3::Y);
+        // perpendicular to the fallback reference, X
+        assert!(hit.tangent.abs_diff_eq(Vec3::Z, 1e-5));
+        assert!(is_right_handed(&hit));
+    }
+
+    #[test]
+    fn reference_up_keeps_the_tangent_horizontal() {
+        let ray = Ray {
+            origin: Vec3::new(0.5, 0.5, 5.0),
+            direction: Vec3::NEG_Z,
+        };
+        let plane = MyPlane::from_mesh_normal(4.0, Vec3::Z);
+        let hit = PlaneHit::compute(ray, CAMERA, CAMERA, &Transform::IDENTITY, &plane)
+            .unwrap()
+            .with_reference_up(Vec3::Y);
+        assert_eq!(hit.tangent.y, 0.0);
+        assert!(hit.tangent.is_normalized());
+        assert!(is_right_handed(&hit));
+    }
+
+    #[test]
+    fn spawn_transform_stands_on_the_surface() {
+        let transform = Transform::from_xyz(0.0, 1.0, 0.0).with_rotation(Quat::from_euler(
+            EulerRot::YXZ,
+            0.7,
+            0.5,
+            0.0,
+        ));
+        let ray = Ray {
+            origin: Vec3::new(0.2, 10.0, 0.1),
+            direction: Vec3::NEG_Y,
+        };
+        let plane = MyPlane::new(4.0);
+        for hit in [
+            PlaneHit::compute(ray, CAMERA, CAMERA, &transform, &plane).unwrap(),
+            hit_from_above(CAMERA, 0.0, 0.5, 0.5).with_reference_up(Vec3::Z),
+        ] {
+            let spawned = hit.spawn_transform(0.5);
+            assert!(spawned.up().abs_diff_eq(hit.normal, 1e-5));
+            assert!(spawned.right().abs_diff_eq(hit.tangent, 1e-5));
+            assert!(spawned
+                .translation
+                .abs_diff_eq(hit.world + hit.normal * 0.5, 1e-5));
+        }
+    }
+
+    #[test]
+    fn placement_at_a_seam_lands_between_the_tiles() {
+        let ray = Ray {
+            origin: Vec3::new(0.0, 10.0, 0.0),
+            direction: Vec3::NEG_Y,
+        };
+        // two tiles meeting at x = 0, the right one a little higher
+        let tile = |entity: u32, x: f32, y: f32| {
+            let transform = Transform::from_xyz(x, y, 0.0);
+            let entity = Entity::from_raw(entity);
+            PlaneHit::compute(ray, CAMERA, entity, &transform, &MyPlane::new(2.0)).unwrap()
+        };
+        let hits = [tile(1, 1.0, 0.02), tile(2, -1.0, 0.0)];
+        assert!(hits.iter().all(|hit| hit.inside));
+
+        let placement = PlacementEvent::from_hits(CAMERA, ray, &hits, 0.1).unwrap();
+        assert!(placement.world.abs_diff_eq(Vec3::new(0.0, 0.01, 0.0), 1e-5));
+        assert_eq!(placement.normal, Vec3::Y);
+        // outside the band, only the nearest counts
+        let placement = PlacementEvent::from_hits(CAMERA, ray, &hits, 0.01).unwrap();
+        assert!(placement.world.abs_diff_eq(Vec3::new(0.0, 0.02, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn local_normal_turns_into_the_world_normal() {
+        let transform = Transform::from_xyz(0.0, 0.5, 0.0).with_rotation(Quat::from_euler(
+            EulerRot::YXZ,
+            0.7,
+            0.5,
+            0.0,
+        ));
+        for plane in [
+            MyPlane::new(4.0),
+            MyPlane::from_mesh_normal(4.0, Vec3::Z),
+            MyPlane {
+                invert_normal: true,
+                ..MyPlane::from_mesh_normal(4.0, Vec3::X)
+            },
+        ] {
+            let rotation = transform.rotation * plane.mesh_rotation;
+            let front = plane.front_normal(&transform);
+            // hit from both sides
+            for origin in [front * 5.0, front * -5.0] {
+                let ray = Ray {
+                    origin: transform.translation + origin,
+                    direction: -origin.normalize(),
+                };
+                let hit = PlaneHit::compute(ray, CAMERA, CAMERA, &transform, &plane).unwrap();
+                let sign = if hit.face == Face::Front { 1.0 } else { -1.0 };
+                assert!((rotation * hit.local_normal * sign).abs_diff_eq(hit.normal, 1e-5));
+                assert!(hit.local_normal.abs_diff_eq(Vec3::Y, 1e-5) != plane.invert_normal);
+            }
+        }
+    }
+
+    #[test]
+    fn inverting_the_normal_turns_a_back_hit_front() {
+        let from_below = Ray {
+            origin: Vec3::new(0.5, -5.0, 0.5),
+            direction: Vec3::Y,
+        };
+        let plane = MyPlane::new(4.0);
+        let inverted = MyPlane {
+            invert_normal: true,
+            ..plane
+        };
+        let back = PlaneHit::compute(from_below, CAMERA, CAMERA, &Transform::IDENTITY, &plane);
+        let front = PlaneHit::compute(from_below, CAMERA, CAMERA, &Transform::IDENTITY, &inverted);
+        let (back, front) = (back.unwrap(), front.unwrap());
+        assert_eq!(back.face, Face::Back);
+        assert_eq!(front.face, Face::Front);
+        // the normal faces the ray either way
+        assert_eq!(back.normal, Vec3::NEG_Y);
+        assert_eq!(front.normal, Vec3::NEG_Y);
+        assert_eq!(front.world, back.world);
+        assert_eq!(front.uv, back.uv);
+        assert!(is_right_handed(&front));
+    }
+
+    #[test]
+    fn axis_distances_on_a_rotated_plane() {
+        let ray = Ray {
+            origin: Vec3::new(1.0, 10.0, 0.5),
+            direction: Vec3::NEG_Y,
+        };
+        let turn = Quat::from_rotation_y(30f32.to_radians());
+        let transform = Transform::from_xyz(0.2, 0.0, 0.1).with_rotation(turn);
+        let plane = MyPlane::new(4.0);
+        let hit = PlaneHit::compute(ray, CAMERA, Entity::PLACEHOLDER, &transform, &plane).unwrap();
+        // the axes turn to (cos 30, 0, -sin 30) and (sin 30, 0, cos 30), and the hit is (0.8, 0.4)
+        // from the plane's center
+        let (sin, cos) = (0.5, 3f32.sqrt() / 2.0);
+        let expected = Vec2::new(0.8 * cos - 0.4 * sin, 0.8 * sin + 0.4 * cos);
+        assert!(hit.axis_distances.abs_diff_eq(expected, 1e-5));
+        assert!(hit.uv.abs_diff_eq(expected / 4.0 + 0.5, 1e-5));
+
+        // stretching the plane leaves the distances in world units, but not the mesh's
+        let stretched = transform.with_scale(Vec3::new(2.0, 1.0, 1.0));
+        let hit = PlaneHit::compute(ray, CAMERA, Entity::PLACEHOLDER, &stretched, &plane).unwrap();
+        assert!(hit.axis_distances.abs_diff_eq(expected, 1e-5));
+        assert!((hit.local_mesh_point.x - expected.x / 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn local_mesh_point_undoes_the_transform() {
+        let transform =
+            Transform::from_xyz(2.0, 1.0, -3.0).with_rotation(Quat::from_rotation_y(0.6));
+        let plane = MyPlane::new(4.0);
+        let ray = Ray {
+            origin: Vec3::new(2.5, 10.0, -2.0),
+            direction: Vec3::NEG_Y,
+        };
+        let hit = PlaneHit::compute(ray, CAMERA, CAMERA, &transform, &plane).unwrap();
+
+        let projected = transform
+            .compute_matrix()
+            .inverse()
+            .transform_point3(hit.world);
+        assert!(hit.local_mesh_point.abs_diff_eq(projected, 1e-5));
+        assert!(hit.local_mesh_point.y.abs() < 1e-5);
+        assert!(plane
+            .local_uv(hit.local_mesh_point)
+            .abs_diff_eq(hit.uv, 1e-5));
+    }
+}
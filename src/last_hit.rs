@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::PlaneHitEvent;
+
+/// The most recent inside hit on a plane, inserted on the plane entity when it's hit.
+///
+/// Only planes that are actually hit are touched, so `Changed<LastHit>` picks out the planes hit
+/// this frame.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct LastHit {
+    pub uv: Vec2,
+    pub world: Vec3,
+    /// [`Time::elapsed`] when the hit happened
+    pub time: Duration,
+}
+
+/// How long a [`LastHit`] stays on a plane before it's removed. `None` keeps it until the next
+/// hit replaces it.
+#[derive(Resource, Clone, Copy, Debug, Default, Deref, DerefMut)]
+pub struct LastHitTimeout(pub Option<Duration>);
+
+/// The cursor ray of the most recent click that missed every plane, i.e. hit no plane inside its
+/// bounds, and `None` once a click hits one again. See [`PlaneMissEvent`] to react to each miss,
+/// e.g. deselecting on a click into empty space.
+#[derive(Resource, Clone, Copy, Debug, Default, Deref, DerefMut)]
+pub struct LastMiss(pub Option<Ray>);
+
+/// Sent for a click that missed every plane, see [`LastMiss`].
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlaneMissEvent {
+    /// The camera the ray was cast from, which identifies the plugin instance that picked it
+    pub camera: Entity,
+    /// The cursor ray of the click
+    pub ray: Ray,
+}
+
+pub(crate) fn record_last_hits(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut hits: EventReader<PlaneHitEvent>,
+    mut q_last_hit: Query<&mut LastHit>,
+) {
+    for PlaneHitEvent { hit, .. } in hits.iter().filter(|event| event.hit.inside) {
+        let last_hit = LastHit {
+            uv: hit.uv,
+            world: hit.world,
+            time: time.elapsed(),
+        };
+        if let Ok(mut existing) = q_last_hit.get_mut(hit.entity) {
+            *existing = last_hit;
+        } else if let Some(mut entity) = commands.get_entity(hit.entity) {
+            entity.insert(last_hit);
+        }
+    }
+}
+
+pub(crate) fn expire_last_hits(
+    mut commands: Commands,
+    time: Res<Time>,
+    timeout: Res<LastHitTimeout>,
+    q_last_hit: Query<(Entity, &LastHit)>,
+) {
+    let Some(timeout) = timeout.0 else {
+        return;
+    };
+    for (entity, last_hit) in q_last_hit.iter() {
+        if time.elapsed().saturating_sub(last_hit.time) > timeout {
+            commands.entity(entity).remove::<LastHit>();
+        }
+    }
+}
@@ -0,0 +1,614 @@
+//! Get the intersection of the cursor with a 3d plane mesh.
+//!
+//! Add [`IntersectPlanePlugin`], tag your camera with [`MainCamera`] and your planes with
+//! [`MyPlane`], and every left click will emit a [`PlaneHitEvent`] for each plane the cursor ray
+//! crosses. By default a small cube marker is spawned at each hit; see [`MarkerSpawner`] to
+//! replace it with your own entities.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+mod analytic;
+mod bounds;
+#[cfg(feature = "channel")]
+mod channel;
+mod click;
+mod color;
+mod config;
+mod debug;
+mod decal;
+mod diagnostics;
+mod drag;
+mod ellipse;
+mod filter;
+mod grid;
+mod heightfield;
+mod highlight;
+mod hit;
+mod last_hit;
+mod marker;
+mod pickable;
+mod picker;
+mod picking;
+mod pin;
+mod plane;
+mod ray;
+mod readout;
+mod screen;
+mod seam;
+#[cfg(feature = "serde")]
+mod session;
+mod spawn;
+#[cfg(test)]
+mod test_util;
+mod uv;
+
+pub use analytic::AnalyticPlanes;
+pub use bounds::{BoundsPredicate, CustomBounds};
+#[cfg(feature = "channel")]
+pub use channel::HitChannel;
+pub use click::{ClickCooldown, OnPlaneClick, PlaneClickCallback};
+pub use config::{
+    CoordSpace, Corner, DesiredNormal, PickMode, PickSettings, PickingConfig, RayOrigin, SelectBy,
+    TieBreak, ToleranceMode,
+};
+pub use debug::DebugOverlay;
+pub use decal::Decal;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::PickDiagnostics;
+pub use drag::{AnchorPick, BoundaryCrossEvent, PlaneCoastEvent, PlaneDragEvent};
+pub use ellipse::{EllipseCoords, MyEllipse};
+pub use filter::{FocusPlane, PickFilter, PickPriority};
+pub use grid::{GridCell, GridLines, GridSnap, GridVertexSnap};
+pub use heightfield::{HeightSampler, Heightfield};
+pub use highlight::{CellHighlight, CellHighlightQuad, HoverHighlight, UnhighlightedMaterial};
+pub use hit::{
+    BlendedHitEvent, Face, Modifiers, PlacementEvent, PlaneHit, PlaneHitEvent, PressSubFrame,
+};
+pub use last_hit::{LastHit, LastHitTimeout, LastMiss, PlaneMissEvent};
+pub use marker::{HitMarker, MarkerConfig, MarkerSpawner, SpawnMarkers};
+pub use pickable::{RaycastHit, Raycastable, RegisterPickable};
+pub use picker::PlanePicker;
+pub use picking::{
+    any_plane_in_ray, cursor_or_camera_changed, planes_changed, HoverUv, HoveredPlane,
+    HoveredPlanes,
+};
+pub use pin::{PinnedPick, PinnedPickEvent};
+pub use plane::{plane_delta, MyPlane};
+pub use ray::{is_ray_parallel, viewport_ray, RayExt};
+pub use readout::{CoordReadout, ReadoutCoords};
+pub use screen::{
+    plane_screen_coverage, plane_screen_rect, planes_overlap_on_screen, project_screen_path,
+};
+pub use seam::BlendHoverEvent;
+#[cfg(feature = "serde")]
+pub use session::{PickRecorder, PickSession, RecordedHit, RecordedPick};
+pub use spawn::spawn_plane_grid;
+pub use uv::{pixel_to_uv, uv_to_pixel, PlaneEdge, UvTransform};
+
+/// Used to help identify our main camera
+#[derive(Component)]
+pub struct MainCamera;
+
+/// The systems the plugin adds, in the order they run.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntersectPlaneSet {
+    /// Cast the cursor ray and send [`PlaneHitEvent`]s
+    Pick,
+    /// Spawn markers for the hits
+    Markers,
+}
+
+/// Picks [`MyPlane`]s under the cursor on click and spawns markers at the hits.
+///
+/// The setters configure the [`PickingConfig`] resource the plugin inserts:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::IntersectPlanePlugin;
+/// App::new().add_plugins((
+///     DefaultPlugins,
+///     IntersectPlanePlugin::default()
+///         .pick_button(MouseButton::Right)
+///         .cull_backfaces(true)
+///         .max_distance(Some(50.0)),
+/// ));
+/// ```
+///
+/// By default the plugin picks planes through the [`MainCamera`]. To pick different sets of
+/// planes through different cameras, e.g. one per viewport in a split-screen editor, add one
+/// instance per camera marker `C` and plane marker `P`. Each instance only picks planes that have
+/// both [`MyPlane`] and `P`, through the camera marked `C`, and has its own
+/// [`PickingConfig<C, P>`]. Hits from all instances share the same events; tell them apart by
+/// [`PlaneHit::camera`].
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::IntersectPlanePlugin;
+/// #[derive(Component)]
+/// struct CamA;
+/// #[derive(Component)]
+/// struct PlanesA;
+/// #[derive(Component)]
+/// struct CamB;
+/// #[derive(Component)]
+/// struct PlanesB;
+///
+/// App::new().add_plugins((
+///     DefaultPlugins,
+///     IntersectPlanePlugin::<CamA, PlanesA>::new(),
+///     IntersectPlanePlugin::<CamB, PlanesB>::new(),
+/// ));
+/// ```
+pub struct IntersectPlanePlugin<C = MainCamera, P = MyPlane> {
+    settings: PickSettings,
+    marker: PhantomData<fn() -> (C, P)>,
+}
+
+/// The instance for the default markers, [`MainCamera`] and [`MyPlane`]. `new()` needs them
+/// named, as `<IntersectPlanePlugin>::new()`, since Rust doesn't fill in default type parameters
+/// by itself.
+impl Default for IntersectPlanePlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Component, P: Component> IntersectPlanePlugin<C, P> {
+    /// An instance picking planes marked `P` through the camera marked `C`.
+    pub fn new() -> Self {
+        Self {
+            settings: PickSettings::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// See [`PickSettings::pick_button`]
+    pub fn pick_button(mut self, button: MouseButton) -> Self {
+        self.settings.pick_button = button;
+        self
+    }
+
+    /// See [`PickSettings::tap_threshold_px`]
+    pub fn tap_threshold_px(mut self, threshold: f32) -> Self {
+        self.settings.tap_threshold_px = threshold;
+        self
+    }
+
+    /// See [`PickSettings::mode`]
+    pub fn mode(mut self, mode: PickMode) -> Self {
+        self.settings.mode = mode;
+        self
+    }
+
+    /// See [`PickSettings::cull_backfaces`]
+    pub fn cull_backfaces(mut self, cull: bool) -> Self {
+        self.settings.cull_backfaces = cull;
+        self
+    }
+
+    /// See [`PickSettings::mirror_back_uv`]
+    pub fn mirror_back_uv(mut self, mirror: bool) -> Self {
+        self.settings.mirror_back_uv = mirror;
+        self
+    }
+
+    /// See [`PickSettings::emit_outside_hits`]
+    pub fn emit_outside_hits(mut self, emit: bool) -> Self {
+        self.settings.emit_outside_hits = emit;
+        self
+    }
+
+    /// See [`PickSettings::frustum_culling`]
+    pub fn frustum_culling(mut self, cull: bool) -> Self {
+        self.settings.frustum_culling = cull;
+        self
+    }
+
+    /// See [`PickSettings::min_screen_size_px`]
+    pub fn min_screen_size_px(mut self, min: Option<f32>) -> Self {
+        self.settings.min_screen_size_px = min;
+        self
+    }
+
+    /// See [`PickSettings::pick_region`]
+    pub fn pick_region(mut self, region: Option<Rect>) -> Self {
+        self.settings.pick_region = region;
+        self
+    }
+
+    /// See [`PickSettings::ray_origin`]
+    pub fn ray_origin(mut self, origin: RayOrigin) -> Self {
+        self.settings.ray_origin = origin;
+        self
+    }
+
+    /// See [`PickSettings::locked_cursor_center`]
+    pub fn locked_cursor_center(mut self, enabled: bool) -> Self {
+        self.settings.locked_cursor_center = enabled;
+        self
+    }
+
+    /// See [`PickSettings::obb_broadphase`]
+    pub fn obb_broadphase(mut self, enabled: bool) -> Self {
+        self.settings.obb_broadphase = enabled;
+        self
+    }
+
+    /// See [`PickSettings::pick_tolerance`]
+    pub fn pick_tolerance(mut self, tolerance: f32) -> Self {
+        self.settings.pick_tolerance = tolerance;
+        self
+    }
+
+    /// See [`PickSettings::tolerance_mode`]
+    pub fn tolerance_mode(mut self, mode: ToleranceMode) -> Self {
+        self.settings.tolerance_mode = mode;
+        self
+    }
+
+    /// See [`PickSettings::shrink_margin`]
+    pub fn shrink_margin(mut self, margin: f32) -> Self {
+        self.settings.shrink_margin = margin;
+        self
+    }
+
+    /// See [`PickSettings::seam_epsilon`]
+    pub fn seam_epsilon(mut self, epsilon: Option<f32>) -> Self {
+        self.settings.seam_epsilon = epsilon;
+        self
+    }
+
+    /// See [`PickSettings::cursor_radius`]
+    pub fn cursor_radius(mut self, radius: f32) -> Self {
+        self.settings.cursor_radius = radius;
+        self
+    }
+
+    /// See [`PickSettings::thickness`]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.settings.thickness = thickness;
+        self
+    }
+
+    /// See [`PickSettings::max_distance`]
+    pub fn max_distance(mut self, distance: Option<f32>) -> Self {
+        self.settings.max_distance = distance;
+        self
+    }
+
+    /// See [`PickSettings::max_hits`]
+    pub fn max_hits(mut self, max: Option<usize>) -> Self {
+        self.settings.max_hits = max;
+        self
+    }
+
+    /// See [`PickSettings::respect_clip_planes`]
+    pub fn respect_clip_planes(mut self, respect: bool) -> Self {
+        self.settings.respect_clip_planes = respect;
+        self
+    }
+
+    /// See [`PickSettings::desired_normal`]
+    pub fn desired_normal(mut self, desired: Option<DesiredNormal>) -> Self {
+        self.settings.desired_normal = desired;
+        self
+    }
+
+    /// See [`PickSettings::accept_mask`]
+    pub fn accept_mask(mut self, mask: u32) -> Self {
+        self.settings.accept_mask = mask;
+        self
+    }
+
+    /// See [`PickSettings::reference_up`]
+    pub fn reference_up(mut self, up: Vec3) -> Self {
+        self.settings.reference_up = up;
+        self
+    }
+
+    /// See [`PickSettings::camera_space_points`]
+    pub fn camera_space_points(mut self, enabled: bool) -> Self {
+        self.settings.camera_space_points = enabled;
+        self
+    }
+
+    /// See [`PickSettings::coord_space`]
+    pub fn coord_space(mut self, space: CoordSpace) -> Self {
+        self.settings.coord_space = space;
+        self
+    }
+
+    /// See [`PickSettings::uv_origin`]
+    pub fn uv_origin(mut self, corner: Corner) -> Self {
+        self.settings.uv_origin = corner;
+        self
+    }
+
+    /// See [`PickSettings::flip_v`]
+    pub fn flip_v(mut self, flip: bool) -> Self {
+        self.settings.flip_v = flip;
+        self
+    }
+
+    /// See [`PickSettings::sample_color`]
+    pub fn sample_color(mut self, enabled: bool) -> Self {
+        self.settings.sample_color = enabled;
+        self
+    }
+
+    /// See [`PickSettings::unit_scale`]
+    pub fn unit_scale(mut self, scale: f32) -> Self {
+        self.settings.unit_scale = scale;
+        self
+    }
+
+    /// See [`PickSettings::tie_break`]
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.settings.tie_break = tie_break;
+        self
+    }
+
+    /// See [`PickSettings::select_by`]
+    pub fn select_by(mut self, select_by: SelectBy) -> Self {
+        self.settings.select_by = select_by;
+        self
+    }
+
+    /// See [`PickSettings::facing_weight`]
+    pub fn facing_weight(mut self, weight: f32) -> Self {
+        self.settings.facing_weight = weight;
+        self
+    }
+
+    /// See [`PickSettings::blend_layers`]
+    pub fn blend_layers(mut self, layers: Option<usize>) -> Self {
+        self.settings.blend_layers = layers;
+        self
+    }
+
+    /// See [`PickSettings::placement_band`]
+    pub fn placement_band(mut self, band: Option<f32>) -> Self {
+        self.settings.placement_band = band;
+        self
+    }
+
+    /// See [`PickSettings::hover_smoothing`]
+    pub fn hover_smoothing(mut self, smoothing: f32) -> Self {
+        self.settings.hover_smoothing = smoothing;
+        self
+    }
+
+    /// See [`PickSettings::blend_seam_distance`]
+    pub fn blend_seam_distance(mut self, distance: Option<f32>) -> Self {
+        self.settings.blend_seam_distance = distance;
+        self
+    }
+
+    /// See [`PickSettings::budget_per_frame`]
+    pub fn budget_per_frame(mut self, budget: Option<usize>) -> Self {
+        self.settings.budget_per_frame = budget;
+        self
+    }
+
+    /// See [`PickSettings::min_alpha`]
+    pub fn min_alpha(mut self, alpha: Option<f32>) -> Self {
+        self.settings.min_alpha = alpha;
+        self
+    }
+
+    /// See [`PickSettings::pin_confirm_key`]
+    pub fn pin_confirm_key(mut self, key: Option<KeyCode>) -> Self {
+        self.settings.pin_confirm_key = key;
+        self
+    }
+
+    /// See [`PickSettings::hover_ignores_dragged`]
+    pub fn hover_ignores_dragged(mut self, enabled: bool) -> Self {
+        self.settings.hover_ignores_dragged = enabled;
+        self
+    }
+
+    /// See [`PickSettings::drag_friction`]
+    pub fn drag_friction(mut self, friction: Option<f32>) -> Self {
+        self.settings.drag_friction = friction;
+        self
+    }
+
+    /// See [`PickSettings::max_planes_per_pick`]
+    pub fn max_planes_per_pick(mut self, max: Option<usize>) -> Self {
+        self.settings.max_planes_per_pick = max;
+        self
+    }
+
+    /// See [`PickSettings::skip_idle_hover`]
+    pub fn skip_idle_hover(mut self, enabled: bool) -> Self {
+        self.settings.skip_idle_hover = enabled;
+        self
+    }
+
+    /// See [`PickSettings::hover_substeps`]
+    pub fn hover_substeps(mut self, substeps: u32) -> Self {
+        self.settings.hover_substeps = substeps;
+        self
+    }
+
+    /// See [`PickSettings::log_picks`]
+    pub fn log_picks(mut self, enabled: bool) -> Self {
+        self.settings.log_picks = enabled;
+        self
+    }
+}
+
+impl<C: Component, P: Component> Plugin for IntersectPlanePlugin<C, P> {
+    fn build(&self, app: &mut App) {
+        // the events and markers are shared by every instance
+        if !app.is_plugin_added::<SharedPlugin>() {
+            app.add_plugins(SharedPlugin);
+        }
+        app.insert_resource(PickingConfig::<C, P>::new(self.settings.clone()))
+            .init_resource::<HoveredPlane<C, P>>()
+            .init_resource::<HoveredPlanes<C, P>>()
+            .init_resource::<HoverUv<C, P>>()
+            .init_resource::<AnchorPick<C, P>>()
+            .init_resource::<PinnedPick<C, P>>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        picking::my_cursor_system::<C, P>,
+                        picking::update_hovered_plane::<C, P>
+                            .run_if(
+                                not(picking::skip_idle_hover::<C, P>)
+                                    .or_else(cursor_or_camera_changed::<C>)
+                                    .or_else(planes_changed::<P>),
+                            )
+                            // so the plane pressed on is already ignored on the press
+                            .after(drag::track_anchor::<C, P>),
+                        drag::track_drags::<C, P>,
+                        drag::track_anchor::<C, P>,
+                        pin::track_pin::<C, P>,
+                    )
+                        .in_set(IntersectPlaneSet::Pick),
+                    highlight::highlight_hovered_plane::<C, P>
+                        .run_if(resource_exists::<HoverHighlight>())
+                        .after(IntersectPlaneSet::Pick),
+                    highlight::highlight_hovered_cell::<C, P>
+                        .run_if(resource_exists::<CellHighlight>())
+                        .after(IntersectPlaneSet::Pick),
+                    grid::draw_hovered_grid::<C, P>
+                        .run_if(resource_exists::<GridLines>())
+                        .after(IntersectPlaneSet::Pick),
+                    seam::send_blend_hover::<C, P>.after(IntersectPlaneSet::Pick),
+                    readout::update_coord_readout::<C, P>
+                        .run_if(resource_exists::<CoordReadout>())
+                        .after(IntersectPlaneSet::Pick),
+                    debug::draw_debug_overlay::<C, P>
+                        .run_if(debug::debug_overlay_enabled)
+                        .after(IntersectPlaneSet::Pick),
+                ),
+            );
+        #[cfg(feature = "debug")]
+        app.add_systems(
+            Update,
+            (
+                debug::draw_raw_nearest::<C, P>.run_if(debug::raw_nearest_enabled),
+                debug::inspect_ray::<C, P>.run_if(debug::debug_overlay_enabled),
+            )
+                .after(IntersectPlaneSet::Pick),
+        );
+        #[cfg(feature = "serde")]
+        app.add_systems(
+            Update,
+            session::record_picks::<C, P>
+                .run_if(resource_exists::<PickRecorder>())
+                .in_set(IntersectPlaneSet::Pick),
+        );
+    }
+}
+
+struct SharedPlugin;
+
+impl Plugin for SharedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlaneHitEvent>()
+            .add_event::<BlendedHitEvent>()
+            .add_event::<PlacementEvent>()
+            .add_event::<PlaneMissEvent>()
+            .add_event::<PinnedPickEvent>()
+            .add_event::<PlaneDragEvent>()
+            .add_event::<PlaneCoastEvent>()
+            .add_event::<BoundaryCrossEvent>()
+            .add_event::<BlendHoverEvent>()
+            .init_resource::<MarkerSpawner>()
+            .init_resource::<MarkerConfig>()
+            .init_resource::<DebugOverlay>()
+            .init_resource::<LastHitTimeout>()
+            .init_resource::<LastMiss>()
+            .init_resource::<PressSubFrame>()
+            .init_resource::<AnalyticPlanes>()
+            .init_resource::<FocusPlane>()
+            .configure_sets(
+                Update,
+                (IntersectPlaneSet::Pick, IntersectPlaneSet::Markers).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    (marker::clear_markers, marker::spawn_markers)
+                        .chain()
+                        .in_set(IntersectPlaneSet::Markers),
+                    marker::billboard_markers
+                        .run_if(marker::billboard_enabled)
+                        .after(IntersectPlaneSet::Markers),
+                    (last_hit::record_last_hits, last_hit::expire_last_hits)
+                        .chain()
+                        .after(IntersectPlaneSet::Pick),
+                    click::dispatch_clicks.after(IntersectPlaneSet::Pick),
+                    hit::clear_press_sub_frame.after(IntersectPlaneSet::Pick),
+                ),
+            );
+        #[cfg(feature = "diagnostics")]
+        PickDiagnostics::register(app);
+        #[cfg(feature = "channel")]
+        app.add_systems(
+            Update,
+            channel::forward_hits
+                .run_if(resource_exists::<HitChannel>())
+                .after(IntersectPlaneSet::Pick),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{
+        bare_app, click_at, events, spawn_camera_with, spawn_plane_with, window_center,
+    };
+
+    #[derive(Component)]
+    struct CamA;
+    #[derive(Component)]
+    struct PlanesA;
+    #[derive(Component)]
+    struct CamB;
+    #[derive(Component)]
+    struct PlanesB;
+
+    #[test]
+    fn instances_only_pick_their_own_planes() {
+        let mut app = bare_app();
+        app.add_plugins((
+            IntersectPlanePlugin::<CamA, PlanesA>::new(),
+            IntersectPlanePlugin::<CamB, PlanesB>::new(),
+        ));
+        let camera_a = spawn_camera_with(&mut app, CamA, Vec3::Y * 5.0, Vec3::ZERO);
+        let camera_b = spawn_camera_with(&mut app, CamB, Vec3::Y * 5.0, Vec3::ZERO);
+        // B's plane is in front of A's, so A would hit it first if it picked it
+        let plane_a = spawn_plane_with(&mut app, PlanesA, 2.0, Transform::IDENTITY);
+        let plane_b = spawn_plane_with(&mut app, PlanesB, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        app.update();
+
+        let center = window_center(&mut app);
+        click_at(&mut app, center);
+
+        let mut hits: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| (event.hit.camera, event.hit.entity))
+            .collect();
+        hits.sort();
+        let mut expected = vec![(camera_a, plane_a), (camera_b, plane_b)];
+        expected.sort();
+        assert_eq!(hits, expected);
+        assert_eq!(
+            **app.world.resource::<HoveredPlane<CamA, PlanesA>>(),
+            Some(plane_a)
+        );
+        assert_eq!(
+            **app.world.resource::<HoveredPlane<CamB, PlanesB>>(),
+            Some(plane_b)
+        );
+    }
+}
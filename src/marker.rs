@@ -0,0 +1,303 @@
+use bevy::prelude::*;
+
+use crate::{Face, GridVertexSnap, MainCamera, MyPlane, PlaneHit, PlaneHitEvent};
+
+/// Spawns a marker entity for every [`PlaneHitEvent`].
+///
+/// The default spawns a small cube at the hit: green when the hit is within the plane, red when
+/// it is outside. To spawn something else, insert your own spawner after adding the plugin:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{IntersectPlanePlugin, MarkerSpawner};
+/// App::new()
+///     .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+///     .insert_resource(MarkerSpawner::new(|commands, hit| {
+///         commands.spawn(SpatialBundle::from_transform(Transform::from_translation(hit.world)));
+///     }));
+/// ```
+///
+/// The spawner only gets [`Commands`], so clone any asset handles it needs into the closure.
+/// Give the spawned entities a [`HitMarker`] so they're cleared along with the default markers.
+///
+/// When many hits arrive at once, e.g. a pick through a stack of planes, prefer
+/// [`MarkerSpawner::batched`], which spawns all of a frame's markers with a single
+/// [`Commands::spawn_batch`].
+#[derive(Resource)]
+pub struct MarkerSpawner(pub Box<SpawnMarkers>);
+
+/// The signature of a [`MarkerSpawner`] callback, called once a frame with all of that frame's
+/// hits.
+pub type SpawnMarkers = dyn Fn(&mut Commands, &[PlaneHit]) + Send + Sync;
+
+/// Tags the markers spawned at hits.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct HitMarker;
+
+/// Settings for the hit markers.
+#[derive(Resource, Clone, Debug)]
+pub struct MarkerConfig {
+    /// Pressing this key despawns every [`HitMarker`]. Defaults to [`KeyCode::Delete`]; `None`
+    /// disables the shortcut.
+    pub clear_key: Option<KeyCode>,
+    /// How far along the hit normal markers are moved off the surface, so they don't z-fight
+    /// with the plane. The hit handed to the [`MarkerSpawner`] has its `world` point moved by
+    /// this much; since the normal faces the ray, that's towards the camera.
+    pub marker_offset: f32,
+    /// Turn every [`HitMarker`] to face the [`MainCamera`] each frame, for flat sprite or quad
+    /// markers. A marker faces the camera with its local +Z axis, like Bevy's `shape::Quad`.
+    pub billboard: bool,
+    /// Turn each marker to lie flat on the plane it was spawned on when it's spawned: its local
+    /// +Y axis along the hit normal, and its X and Z axes along the plane's edges, following the
+    /// plane's rotation. For markers that just need to sit on the surface, rather than follow
+    /// the hit's tangent frame as with [`PlaneHit::spawn_transform`].
+    ///
+    /// The rotation is handed to the [`MarkerSpawner`] as [`PlaneHit::marker_rotation`], which
+    /// the default cubes are spawned with. Custom spawners pick it up from there.
+    pub flat_on_plane: bool,
+    /// Only spawn markers for hits inside a plane's bounds, leaving out the red markers of hits
+    /// outside them. The [`PlaneHitEvent`]s are still sent for every hit.
+    pub markers_only_inside: bool,
+    /// Move the markers of hits outside a plane's bounds onto the nearest point of the plane,
+    /// see [`MyPlane::nearest_point`], so sloppy clicks still leave markers on the surface. The
+    /// hits keep `inside` false, so the default markers are still red.
+    pub clamp_outside: bool,
+}
+
+impl Default for MarkerConfig {
+    fn default() -> Self {
+        Self {
+            clear_key: Some(KeyCode::Delete),
+            marker_offset: 0.001,
+            billboard: false,
+            flat_on_plane: false,
+            markers_only_inside: false,
+            clamp_outside: false,
+        }
+    }
+}
+
+impl MarkerSpawner {
+    /// Call `spawner` for each hit.
+    pub fn new(spawner: impl Fn(&mut Commands, &PlaneHit) + Send + Sync + 'static) -> Self {
+        Self(Box::new(move |commands, hits| {
+            for hit in hits {
+                spawner(commands, hit);
+            }
+        }))
+    }
+
+    /// Spawn the bundle `bundle` makes for each hit, all in one batch.
+    pub fn batched<B: Bundle>(bundle: impl Fn(&PlaneHit) -> B + Send + Sync + 'static) -> Self {
+        Self(Box::new(move |commands, hits| {
+            commands.spawn_batch(hits.iter().map(&bundle).collect::<Vec<_>>());
+        }))
+    }
+}
+
+impl FromWorld for MarkerSpawner {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Mesh::from(shape::Cube { size: 0.1 }));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let inside = materials.add(Color::rgb(0.1, 0.8, 0.2).into());
+        let outside = materials.add(Color::rgb(0.8, 0.1, 0.2).into());
+        Self::batched(move |hit| {
+            // cube
+            (
+                PbrBundle {
+                    mesh: mesh.clone(),
+                    material: if hit.inside {
+                        inside.clone()
+                    } else {
+                        outside.clone()
+                    },
+                    transform: Transform {
+                        translation: hit.world,
+                        rotation: hit.marker_rotation.unwrap_or_default(),
+                        ..default()
+                    },
+                    ..default()
+                },
+                HitMarker,
+            )
+        })
+    }
+}
+
+pub(crate) fn spawn_markers(
+    mut commands: Commands,
+    config: Res<MarkerConfig>,
+    spawner: Res<MarkerSpawner>,
+    mut hits: EventReader<PlaneHitEvent>,
+    q_vertex_snap: Query<(&GlobalTransform, &MyPlane, &GridVertexSnap)>,
+    q_transform: Query<(&GlobalTransform, Option<&MyPlane>)>,
+) {
+    let hits: Vec<PlaneHit> = hits
+        .iter()
+        .filter(|event| event.hit.inside || !config.markers_only_inside)
+        .map(|PlaneHitEvent { hit, .. }| {
+            let world = match q_vertex_snap.get(hit.entity) {
+                Ok((transform, plane, snap)) => {
+                    // the uv picking reports may be remapped, so go by where the hit really is
+                    let uv = plane.local_uv(hit.local_mesh_point);
+                    let transform = transform.compute_transform();
+                    plane.point_at_uv(&transform, snap.vertex_uv(snap.vertex(uv)))
+                }
+                Err(_) => match q_transform.get(hit.entity) {
+                    Ok((transform, Some(plane))) if config.clamp_outside && !hit.inside => {
+                        plane.nearest_point(&transform.compute_transform(), hit.world)
+                    }
+                    _ => hit.world,
+                },
+            };
+            let marker_rotation = q_transform
+                .get(hit.entity)
+                .ok()
+                .filter(|_| config.flat_on_plane)
+                .map(|plane| flat_rotation(hit, plane));
+            PlaneHit {
+                world: world + hit.normal * config.marker_offset,
+                marker_rotation,
+                ..*hit
+            }
+        })
+        .collect();
+    if !hits.is_empty() {
+        (spawner.0)(&mut commands, &hits);
+    }
+}
+
+/// The rotation laying a marker flat on the plane `hit` landed on, from the plane's
+/// `GlobalTransform`, see [`MarkerConfig::flat_on_plane`].
+fn flat_rotation(hit: &PlaneHit, (transform, plane): (&GlobalTransform, Option<&MyPlane>)) -> Quat {
+    let transform = transform.compute_transform();
+    let mut rotation = plane
+        .map_or(transform, |plane| plane.oriented(&transform))
+        .rotation;
+    // the back face's normal points the other way
+    if hit.face == Face::Back {
+        rotation *= Quat::from_rotation_x(std::f32::consts::PI);
+    }
+    rotation
+}
+
+pub(crate) fn clear_markers(
+    mut commands: Commands,
+    config: Res<MarkerConfig>,
+    keys: Res<Input<KeyCode>>,
+    q_marker: Query<Entity, With<HitMarker>>,
+) {
+    if !config.clear_key.is_some_and(|key| keys.just_pressed(key)) {
+        return;
+    }
+    for entity in q_marker.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub(crate) fn billboard_enabled(config: Res<MarkerConfig>) -> bool {
+    config.billboard
+}
+
+pub(crate) fn billboard_markers(
+    q_camera: Query<&GlobalTransform, With<MainCamera>>,
+    mut q_marker: Query<(&mut Transform, &GlobalTransform), With<HitMarker>>,
+) {
+    let Ok(camera_transform) = q_camera.get_single() else {
+        return;
+    };
+    for (mut transform, global_transform) in q_marker.iter_mut() {
+        let away = global_transform.translation() - camera_transform.translation();
+        if away.length_squared() <= f32::EPSILON {
+            continue;
+        }
+        // looking away from the camera points -Z away from it, so +Z faces it
+        let rotation = Transform::IDENTITY
+            .looking_to(away, camera_transform.up())
+            .rotation;
+        // markers may be parented, so undo the parent's rotation
+        let parent_rotation =
+            global_transform.to_scale_rotation_translation().1 * transform.rotation.inverse();
+        transform.rotation = parent_rotation.inverse() * rotation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{click_at, headless_app, screen_point, spawn_camera, spawn_plane};
+
+    /// The transforms of every [`HitMarker`].
+    fn markers(app: &mut App) -> Vec<Transform> {
+        app.world
+            .query_filtered::<&Transform, With<HitMarker>>()
+            .iter(&app.world)
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn flat_markers_lie_on_tilted_planes() {
+        let mut app = headless_app();
+        app.world.resource_mut::<MarkerConfig>().flat_on_plane = true;
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let tilt = Quat::from_rotation_z(0.4) * Quat::from_rotation_y(0.3);
+        spawn_plane(&mut app, 4.0, Transform::from_rotation(tilt));
+        app.update();
+        let position = screen_point(&mut app, camera, Vec3::ZERO);
+        click_at(&mut app, position);
+
+        let [marker] = markers(&mut app)[..] else {
+            panic!("one marker");
+        };
+        assert!(marker.rotation.abs_diff_eq(tilt, 1e-5));
+        assert!(marker.up().abs_diff_eq(tilt * Vec3::Y, 1e-5));
+    }
+
+    #[test]
+    fn markers_only_inside_leaves_out_outside_hits() {
+        for (only_inside, expected) in [(false, 2), (true, 1)] {
+            let mut app = headless_app();
+            app.world.resource_mut::<MarkerConfig>().markers_only_inside = only_inside;
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+            app.update();
+            for point in [Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0)] {
+                let position = screen_point(&mut app, camera, point);
+                click_at(&mut app, position);
+                app.update();
+            }
+
+            assert_eq!(
+                markers(&mut app).len(),
+                expected,
+                "only inside: {only_inside}"
+            );
+        }
+    }
+
+    #[test]
+    fn clamped_outside_markers_land_on_the_edge() {
+        let mut app = headless_app();
+        let mut config = app.world.resource_mut::<MarkerConfig>();
+        config.clamp_outside = true;
+        config.marker_offset = 0.0;
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let position = screen_point(&mut app, camera, Vec3::new(2.5, 0.0, 0.5));
+        click_at(&mut app, position);
+
+        let [marker] = markers(&mut app)[..] else {
+            panic!("one marker");
+        };
+        let edge = Vec3::new(1.0, 0.0, 0.5);
+        assert!(
+            marker.translation.abs_diff_eq(edge, 1e-3),
+            "{}",
+            marker.translation
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+
+use crate::{
+    picking::{my_cursor_system, ClickInput},
+    Face, IntersectPlaneSet, MainCamera, Modifiers, MyPlane, PlaneHit, PlanePicker,
+};
+
+/// A surface other than a [`MyPlane`] that clicks can pick, once registered with
+/// [`RegisterPickable::register_pickable`].
+pub trait Raycastable: Component {
+    /// Where `ray` crosses the surface, with the surface placed by `transform`, or `None` if it
+    /// doesn't. Report hits behind the ray's origin as `None` too.
+    ///
+    /// `transform` is the surface's `GlobalTransform`, so it's in world space even for surfaces
+    /// parented to other entities.
+    fn raycast(&self, ray: Ray, transform: &Transform) -> Option<RaycastHit>;
+}
+
+/// Where a ray crossed a [`Raycastable`] surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RaycastHit {
+    /// Distance along the ray to the crossing
+    pub distance: f32,
+    /// The surface normal in world space at the crossing, pointing out of its front. It's flipped
+    /// to face the ray for hits on the back, as for planes.
+    pub normal: Vec3,
+    /// Where on the surface the crossing is, in whatever coordinates suit it
+    pub uv: Vec2,
+    /// Whether the crossing lies within the surface, rather than on some extension of it
+    pub inside: bool,
+}
+
+/// Adds picking of custom [`Raycastable`] surfaces to an [`App`].
+pub trait RegisterPickable {
+    /// On click, test every `S` against the cursor ray of the default
+    /// [`IntersectPlanePlugin`](crate::IntersectPlanePlugin) instance, so custom surfaces get
+    /// markers and click callbacks like planes. Add the plugin first.
+    ///
+    /// The hits are sorted in among the plane hits, front to back, and narrowed down by
+    /// [`PickSettings::mode`](crate::PickSettings::mode) with them before any
+    /// [`PlaneHitEvent`](crate::PlaneHitEvent) is sent, so in
+    /// [`PickMode::Closest`](crate::PickMode::Closest) a surface behind the closest plane isn't
+    /// reported. Hover ignores them.
+    fn register_pickable<S: Raycastable>(&mut self) -> &mut Self;
+}
+
+impl RegisterPickable for App {
+    fn register_pickable<S: Raycastable>(&mut self) -> &mut Self {
+        if !self.world.contains_resource::<ShapeHits>() {
+            self.init_resource::<ShapeHits>()
+                .add_systems(First, clear_shape_hits);
+        }
+        self.add_systems(
+            Update,
+            pick_shapes::<S>
+                .in_set(IntersectPlaneSet::Pick)
+                .before(my_cursor_system::<MainCamera, MyPlane>),
+        )
+    }
+}
+
+/// This frame's click hits on registered [`Raycastable`] surfaces, which the default instance's
+/// click sorts in among its plane hits. Behind a lock, like [`PickCounters`], so the shapes can
+/// add to it while [`PlanePicker`] reads it.
+///
+/// [`PickCounters`]: crate::diagnostics::PickCounters
+#[derive(Resource, Default)]
+pub(crate) struct ShapeHits(Mutex<Vec<PlaneHit>>);
+
+impl ShapeHits {
+    /// The hits so far this frame.
+    pub(crate) fn get(&self) -> Vec<PlaneHit> {
+        self.0
+            .lock()
+            .map_or_else(|_| Vec::new(), |hits| hits.clone())
+    }
+}
+
+fn clear_shape_hits(mut shape_hits: ResMut<ShapeHits>) {
+    if let Ok(hits) = shape_hits.0.get_mut() {
+        hits.clear();
+    }
+}
+
+fn pick_shapes<S: Raycastable>(
+    picker: PlanePicker,
+    input: ClickInput,
+    q_shape: Query<(Entity, &GlobalTransform, &S)>,
+    shape_hits: Res<ShapeHits>,
+) {
+    let config = picker.config();
+    let Some(pointer) = input.click(config) else {
+        return;
+    };
+    let (Some(camera), Some(ray)) = (picker.camera(), picker.pointer_ray(pointer)) else {
+        return;
+    };
+
+    let hits = q_shape
+        .iter()
+        .filter_map(|(entity, transform, shape)| {
+            let transform = &transform.compute_transform();
+            let hit = shape.raycast(ray, transform)?;
+            let (face, sign) = if ray.direction.dot(hit.normal) > 0.0 {
+                (Face::Back, -1.0)
+            } else {
+                (Face::Front, 1.0)
+            };
+            let world = ray.get_point(hit.distance);
+            // in world units, unlike `to_local`
+            let along_axes = transform.rotation.inverse() * (world - transform.translation);
+            Some(
+                PlaneHit {
+                    entity,
+                    camera,
+                    world,
+                    camera_space_point: None,
+                    reference_point: None,
+                    local_mesh_point: MyPlane::to_local(transform, world),
+                    axis_distances: Vec2::new(along_axes.x, along_axes.z),
+                    uv: hit.uv,
+                    coords: hit.uv,
+                    inside: hit.inside,
+                    distance: hit.distance,
+                    unit_scale: config.unit_scale,
+                    priority: 0,
+                    face,
+                    normal: hit.normal * sign,
+                    local_normal: transform.rotation.inverse() * hit.normal,
+                    // replaced by `with_reference_up`
+                    tangent: Vec3::ZERO,
+                    bitangent: Vec3::ZERO,
+                    sampled_color: None,
+                    sub_frame: 1.0,
+                    modifiers: Modifiers::default(),
+                    cursor_ndc: None,
+                    marker_rotation: None,
+                    ellipse: None,
+                }
+                .with_reference_up(config.reference_up),
+            )
+        })
+        .filter(|hit| !(config.cull_backfaces && hit.face == Face::Back))
+        .filter(|hit| config.max_distance.is_none_or(|max| hit.distance <= max));
+    if let Ok(mut shape_hits) = shape_hits.0.lock() {
+        shape_hits.extend(hits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{app_with, click_at, events, spawn_camera, spawn_plane, window_center},
+        IntersectPlanePlugin, PickMode, PlaneHitEvent,
+    };
+
+    /// A disc facing +Y
+    #[derive(Component)]
+    struct Disc(f32);
+
+    impl Raycastable for Disc {
+        fn raycast(&self, ray: Ray, transform: &Transform) -> Option<RaycastHit> {
+            let normal = transform.up();
+            let distance = ray.intersect_plane(transform.translation, normal)?;
+            let offset = ray.get_point(distance) - transform.translation;
+            Some(RaycastHit {
+                distance,
+                normal,
+                uv: Vec2::ZERO,
+                inside: offset.length() <= self.0,
+            })
+        }
+    }
+
+    /// What a click in the middle of the view reports in `PickMode::Closest`, looking down on a
+    /// plane at the origin and a disc at `disc_height`, and the plane's and disc's entities.
+    fn closest_over(disc_height: f32) -> (Vec<Entity>, Entity, Entity) {
+        let mut app = app_with(IntersectPlanePlugin::default().mode(PickMode::Closest));
+        app.register_pickable::<Disc>();
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let plane = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        let transform = Transform::from_xyz(0.0, disc_height, 0.0);
+        let disc = app
+            .world
+            .spawn((Disc(1.0), transform, GlobalTransform::from(transform)))
+            .id();
+        app.update();
+        let center = window_center(&mut app);
+        click_at(&mut app, center);
+        let hits = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| event.hit.entity)
+            .collect();
+        (hits, plane, disc)
+    }
+
+    #[test]
+    fn closest_mode_drops_shapes_behind_the_closest_plane() {
+        let (hits, plane, _) = closest_over(-1.0);
+        assert_eq!(hits, [plane]);
+    }
+
+    #[test]
+    fn closest_mode_picks_shapes_in_front_of_planes() {
+        let (hits, _, disc) = closest_over(1.0);
+        assert_eq!(hits, [disc]);
+    }
+}
@@ -0,0 +1,533 @@
+use std::sync::atomic::Ordering;
+
+use bevy::{ecs::system::SystemParam, prelude::*, utils::Instant, window::PrimaryWindow};
+
+use crate::{
+    diagnostics::PickCounters,
+    pickable::ShapeHits,
+    picking::{
+        apply_mode, cursor_position, cursor_ray, order_hits, pick_planes, touch_position,
+        with_origin, PickCamera, PickableCamera, PickablePlane, Pointer, TieKey,
+    },
+    viewport_ray, AnalyticPlanes, FocusPlane, MainCamera, MyPlane, PickMode, PickingConfig,
+    PlaneHit, PlaneHitEvent,
+};
+
+/// Picks planes from your own systems, using the same camera, window and [`PickingConfig`] as
+/// the [`IntersectPlanePlugin`](crate::IntersectPlanePlugin) instance for camera marker `C` and
+/// plane marker `P`.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::PlanePicker;
+/// fn print_hovered(picker: PlanePicker) {
+///     if let Some(hit) = picker.pick_closest() {
+///         info!("hovering {:?} at {}", hit.entity, hit.uv);
+///     }
+/// }
+/// ```
+///
+/// For another instance, name its markers: `PlanePicker<CamA, PlanesA>`.
+#[derive(SystemParam)]
+pub struct PlanePicker<'w, 's, C: Component = MainCamera, P: Component = MyPlane> {
+    config: Res<'w, PickingConfig<C, P>>,
+    q_window: Query<'w, 's, (Entity, &'static Window, Option<&'static PrimaryWindow>)>,
+    q_camera: Query<'w, 's, PickableCamera, With<C>>,
+    q_plane: Query<'w, 's, PickablePlane, With<P>>,
+    // optional so picking works without the PBR plugin
+    materials: Option<Res<'w, Assets<StandardMaterial>>>,
+    analytic_planes: Option<Res<'w, AnalyticPlanes>>,
+    focus: Res<'w, FocusPlane>,
+    q_reference: Query<'w, 's, &'static GlobalTransform>,
+    q_name: Query<'w, 's, &'static Name>,
+    // only inserted with the `diagnostics` feature
+    counters: Option<Res<'w, PickCounters>>,
+    // only inserted once a shape is registered with `register_pickable`
+    shape_hits: Option<Res<'w, ShapeHits>>,
+}
+
+impl<'w, 's, C: Component, P: Component> PlanePicker<'w, 's, C, P> {
+    /// The settings picks are made with.
+    pub fn config(&self) -> &PickingConfig<C, P> {
+        &self.config
+    }
+
+    /// The [`Name`] of `entity`, e.g. a hit's plane, if it has one, for readable logs and UI.
+    pub fn name(&self, entity: Entity) -> Option<&str> {
+        self.q_name.get(entity).ok().map(Name::as_str)
+    }
+
+    /// The event to send for `hit`, with its plane's [`Name`], for systems sending
+    /// [`PlaneHitEvent`]s of their own picks.
+    pub fn hit_event(&self, hit: PlaneHit) -> PlaneHitEvent {
+        PlaneHitEvent {
+            hit,
+            name: self.name(hit.entity).map(String::from),
+        }
+    }
+
+    /// The camera picks are made through, if there's exactly one.
+    pub fn camera(&self) -> Option<Entity> {
+        self.q_camera.get_single().ok().map(|camera| camera.entity)
+    }
+
+    /// The ray from the camera through the cursor, if the cursor is in the window and the camera
+    /// renders to it: the [`PickSettings::window`](crate::PickSettings::window), or the primary
+    /// window by default. While the cursor is locked this is the middle of the viewport instead,
+    /// see [`PickSettings::locked_cursor_center`](crate::PickSettings::locked_cursor_center).
+    ///
+    /// This goes through [`Camera::viewport_to_world`], so it works with any projection,
+    /// including custom ones.
+    pub fn cursor_ray(&self) -> Option<Ray> {
+        let camera = self.q_camera.get_single().ok()?;
+        let ray = cursor_ray(
+            self.window()?,
+            self.primary_window(),
+            camera.camera,
+            camera.transform,
+            &self.config,
+        )?;
+        self.with_origin(ray, camera.transform)
+    }
+
+    /// `ray`, from the camera's near plane, moved to the
+    /// [`PickSettings::ray_origin`](crate::PickSettings::ray_origin).
+    fn with_origin(&self, ray: Ray, camera_transform: &GlobalTransform) -> Option<Ray> {
+        with_origin(
+            ray,
+            camera_transform,
+            self.config.ray_origin,
+            &self.q_reference,
+        )
+    }
+
+    /// The point `distance` along the [`cursor_ray`](PlanePicker::cursor_ray), whatever planes
+    /// there are, e.g. to place an object a fixed distance out from the camera. The distance is
+    /// from the ray's origin, see [`PickSettings::ray_origin`](crate::PickSettings::ray_origin).
+    pub fn cursor_point_at_distance(&self, distance: f32) -> Option<Vec3> {
+        Some(self.cursor_ray()?.get_point(distance))
+    }
+
+    /// The point on the [`cursor_ray`](PlanePicker::cursor_ray) `depth` in front of the camera,
+    /// measured along its view direction rather than the ray, so points at the same depth lie on
+    /// a plane facing the camera whatever the cursor position. `None` if that plane is behind the
+    /// ray's origin, e.g. closer than the near clip plane.
+    pub fn cursor_point_at_depth(&self, depth: f32) -> Option<Vec3> {
+        let ray = self.cursor_ray()?;
+        let camera = self.q_camera.get_single().ok()?;
+        let forward = camera.transform.forward();
+        let origin = camera.transform.translation() + forward * depth;
+        let distance = ray.intersect_plane(origin, forward)?;
+        Some(ray.get_point(distance))
+    }
+
+    /// Where the cursor is in the camera's viewport, in logical pixels, under the same conditions
+    /// as [`PlanePicker::cursor_ray`].
+    pub(crate) fn cursor_position(&self) -> Option<Vec2> {
+        let camera = self.q_camera.get_single().ok()?;
+        cursor_position(
+            self.window()?,
+            self.primary_window(),
+            camera.camera,
+            &self.config,
+        )
+    }
+
+    /// The ray from the camera through `position` in its viewport, in logical pixels.
+    pub(crate) fn viewport_ray(&self, position: Vec2) -> Option<Ray> {
+        let camera = self.q_camera.get_single().ok()?;
+        let ray = viewport_ray(camera.camera, camera.transform, position)?;
+        self.with_origin(ray, camera.transform)
+    }
+
+    /// Where the cursor is in the camera's viewport in normalized device coordinates, see
+    /// [`PlaneHit::cursor_ndc`], under the same conditions as [`PlanePicker::cursor_ray`].
+    pub fn cursor_ndc(&self) -> Option<Vec2> {
+        self.viewport_ndc(self.cursor_position()?)
+    }
+
+    /// `position` in the camera's viewport, in logical pixels, in normalized device coordinates.
+    pub(crate) fn viewport_ndc(&self, position: Vec2) -> Option<Vec2> {
+        let camera = self.q_camera.get_single().ok()?;
+        let size = camera
+            .camera
+            .logical_viewport_size()
+            .filter(|size| size.min_element() > 0.0)?;
+        // viewport pixels run top to bottom
+        let ndc = position / size * 2.0 - 1.0;
+        Some(Vec2::new(ndc.x, -ndc.y))
+    }
+
+    /// The ray from the camera through `pointer`: the [`cursor_ray`](PlanePicker::cursor_ray),
+    /// or through a touch.
+    pub(crate) fn pointer_ray(&self, pointer: Pointer) -> Option<Ray> {
+        match pointer {
+            Pointer::Cursor => self.cursor_ray(),
+            Pointer::Touch(position) => self.viewport_ray(self.touch_position(position)?),
+        }
+    }
+
+    /// Where `pointer` is in the camera's viewport in normalized device coordinates.
+    pub(crate) fn pointer_ndc(&self, pointer: Pointer) -> Option<Vec2> {
+        match pointer {
+            Pointer::Cursor => self.cursor_ndc(),
+            Pointer::Touch(position) => self.viewport_ndc(self.touch_position(position)?),
+        }
+    }
+
+    /// Where a touch at `position` in the primary window is in the camera's viewport, if the
+    /// camera renders to that window. Touches are only reported for the primary window.
+    fn touch_position(&self, position: Vec2) -> Option<Vec2> {
+        let camera = self.q_camera.get_single().ok()?;
+        touch_position(
+            self.primary_window()?,
+            camera.camera,
+            &self.config,
+            position,
+        )
+    }
+
+    /// The window with [`PrimaryWindow`], if there is one.
+    fn primary_window(&self) -> Option<Entity> {
+        self.q_window
+            .iter()
+            .find(|(.., primary)| primary.is_some())
+            .map(|(entity, ..)| entity)
+    }
+
+    /// The window the cursor is read from, see [`PickSettings::window`].
+    fn window(&self) -> Option<(Entity, &Window)> {
+        let (entity, window, _) = match self.config.window {
+            Some(entity) => self.q_window.get(entity).ok()?,
+            None => self
+                .q_window
+                .iter()
+                .find(|(.., primary)| primary.is_some())?,
+        };
+        Some((entity, window))
+    }
+
+    /// Every plane hit under the cursor, front to back, including hits outside the plane
+    /// bounds. Ignores [`PickSettings::mode`](crate::PickSettings::mode).
+    pub fn pick_all(&self) -> Vec<PlaneHit> {
+        let Some(ray) = self.cursor_ray() else {
+            return Vec::new();
+        };
+        let mut hits = self.pick_ray(ray);
+        self.set_cursor_ndc(&mut hits, self.cursor_ndc());
+        hits
+    }
+
+    /// The closest plane under the cursor whose bounds contain the hit, as in
+    /// [`PickMode::Closest`](crate::PickMode::Closest).
+    pub fn pick_closest(&self) -> Option<PlaneHit> {
+        let mut hit = self.pick_closest_ray(self.cursor_ray()?)?;
+        hit.cursor_ndc = self.cursor_ndc();
+        Some(hit)
+    }
+
+    /// The hits under the cursor that [`PickSettings::mode`](crate::PickSettings::mode) reports.
+    pub fn pick(&self) -> Vec<PlaneHit> {
+        let Some(ray) = self.cursor_ray() else {
+            return Vec::new();
+        };
+        let mut hits = self.pick_ray(ray);
+        apply_mode(self.config.mode, &self.config, ray, &mut hits);
+        self.set_cursor_ndc(&mut hits, self.cursor_ndc());
+        hits
+    }
+
+    /// The closest plane at `position` in the viewport, in logical pixels, as in
+    /// [`PlanePicker::pick_closest`] but for any point rather than the cursor, e.g. for a tooltip
+    /// over a UI element.
+    ///
+    /// Picks immediately, through this picker's camera: the [`MainCamera`](crate::MainCamera) for
+    /// the default instance.
+    pub fn pick_at_pixel(&self, position: Vec2) -> Option<PlaneHit> {
+        let mut hit = self.pick_closest_ray(self.viewport_ray(position)?)?;
+        hit.cursor_ndc = self.viewport_ndc(position);
+        Some(hit)
+    }
+
+    /// Set the [`PlaneHit::cursor_ndc`] of `hits`, picked under the cursor at `ndc`.
+    pub(crate) fn set_cursor_ndc(&self, hits: &mut [PlaneHit], ndc: Option<Vec2>) {
+        for hit in hits {
+            hit.cursor_ndc = ndc;
+        }
+    }
+
+    /// Sort this frame's click hits on registered [`Raycastable`](crate::Raycastable) surfaces
+    /// in among the front-to-back `hits` of this instance's click.
+    pub(crate) fn add_shape_hits(&self, hits: &mut Vec<PlaneHit>) {
+        let (Some(shape_hits), Some(camera)) = (self.shape_hits.as_deref(), self.camera()) else {
+            return;
+        };
+        hits.extend(
+            shape_hits
+                .get()
+                .into_iter()
+                .filter(|hit| hit.camera == camera),
+        );
+        // stable, so equidistant planes keep their tie-break order
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    }
+
+    /// The closest plane hit by `ray` whose bounds contain the hit, as in
+    /// [`PlanePicker::pick_closest`].
+    pub fn pick_closest_ray(&self, ray: Ray) -> Option<PlaneHit> {
+        let mut hits = self.pick_ray(ray);
+        apply_mode(PickMode::Closest, &self.config, ray, &mut hits);
+        hits.pop()
+    }
+
+    /// Every plane hit by `ray`, front to back, as in [`PlanePicker::pick_all`].
+    pub fn pick_ray(&self, ray: Ray) -> Vec<PlaneHit> {
+        self.pick_ray_chunk(ray, 0, usize::MAX).0
+    }
+
+    /// Every plane hit by the ray from `origin` along `direction`, front to back, as in
+    /// [`PlanePicker::pick_ray`], for rays typed in or computed rather than cast from the camera,
+    /// e.g. a debug console's `pick` command. `direction` needn't be normalized; a zero
+    /// `direction` hits nothing.
+    ///
+    /// The settings that depend on the camera, like frustum culling, still use this picker's
+    /// camera, so without one this hits nothing either.
+    pub fn pick_from_world_ray(&self, origin: Vec3, direction: Vec3) -> Vec<PlaneHit> {
+        let direction = direction.normalize_or_zero();
+        if direction == Vec3::ZERO {
+            return Vec::new();
+        }
+        self.pick_ray(Ray { origin, direction })
+    }
+
+    /// `hits` from several [`PlanePicker::pick_ray_chunk`]s in the order [`PlanePicker::pick_ray`]
+    /// would have put them in.
+    pub(crate) fn order_hits(&self, hits: Vec<PlaneHit>) -> Vec<PlaneHit> {
+        let keyed = hits
+            .into_iter()
+            .map(|hit| {
+                let material = self
+                    .q_plane
+                    .get(hit.entity)
+                    .ok()
+                    .and_then(|plane| plane.material)
+                    .zip(self.materials.as_deref())
+                    .and_then(|(handle, materials)| materials.get(handle));
+                (hit, TieKey::new(&self.config, &hit, material))
+            })
+            .collect();
+        order_hits(&self.config, keyed)
+    }
+
+    /// Like [`PlanePicker::pick_ray`], but only testing `count` planes starting from the `start`th
+    /// in query order, and the analytic planes along with the first chunk. Also returns how many
+    /// planes were tested, fewer than `count` once the end is reached.
+    pub(crate) fn pick_ray_chunk(
+        &self,
+        ray: Ray,
+        start: usize,
+        count: usize,
+    ) -> (Vec<PlaneHit>, usize) {
+        let Ok(camera) = self.q_camera.get_single() else {
+            return (Vec::new(), 0);
+        };
+        if let Some(max) = self.config.max_planes_per_pick {
+            let planes = self.q_plane.iter().len();
+            let warned = &self.config.warned_plane_limit;
+            if planes > max {
+                if !warned.swap(true, Ordering::Relaxed) {
+                    warn!("{planes} planes to pick, over the limit of {max}, so nothing is picked");
+                }
+                return (Vec::new(), 0);
+            }
+            // warn again the next time it goes over
+            warned.store(false, Ordering::Relaxed);
+        }
+        let start_time = self.counters.is_some().then(Instant::now);
+        let camera = PickCamera::from(camera);
+        let focus = self.focus.0;
+        let mut tested = 0;
+        let mut hits = pick_planes(
+            &self.config,
+            ray,
+            camera,
+            self.materials.as_deref(),
+            self.q_plane
+                .iter()
+                .skip(start)
+                .take(count)
+                .inspect(|_| tested += 1)
+                .filter(|plane| focus.is_none_or(|focus| plane.entity == focus)),
+            self.analytic_planes
+                .as_deref()
+                .filter(|_| focus.is_none() && start == 0),
+        );
+        if self.config.camera_space_points {
+            let world_to_camera = camera.transform.compute_matrix().inverse();
+            for hit in &mut hits {
+                hit.camera_space_point = Some(world_to_camera.transform_point3(hit.world));
+            }
+        }
+        let reference = self
+            .config
+            .reference_frame
+            .and_then(|entity| self.q_reference.get(entity).ok());
+        if let Some(reference) = reference {
+            let world_to_reference = reference.compute_matrix().inverse();
+            for hit in &mut hits {
+                hit.reference_point = Some(world_to_reference.transform_point3(hit.world));
+            }
+        }
+        if let (Some(counters), Some(start_time)) = (&self.counters, start_time) {
+            counters.add(tested, hits.len(), start_time.elapsed());
+        }
+        (hits, tested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{
+        headless_app, move_cursor, run, screen_point, spawn_camera, spawn_plane, window_center,
+    };
+
+    #[test]
+    fn hit_event_names_only_named_planes() {
+        let mut app = headless_app();
+        spawn_camera(&mut app, Vec3::Y * 5.0, Vec3::ZERO);
+        let named = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.world.entity_mut(named).insert(Name::new("floor"));
+        let unnamed = spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+
+        let events = run(&mut app, |picker: PlanePicker| {
+            picker
+                .pick_from_world_ray(Vec3::Y * 5.0, Vec3::NEG_Y)
+                .into_iter()
+                .map(|hit| picker.hit_event(hit))
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].hit.entity, unnamed);
+        assert_eq!(events[0].name, None);
+        assert_eq!(events[1].hit.entity, named);
+        assert_eq!(events[1].name.as_deref(), Some("floor"));
+    }
+
+    #[test]
+    fn head_on_hits_are_in_front_of_the_camera() {
+        let mut app = headless_app();
+        app.world
+            .resource_mut::<PickingConfig>()
+            .camera_space_points = true;
+        spawn_camera(&mut app, Vec3::Y * 5.0, Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+
+        let hit = run(&mut app, |picker: PlanePicker| {
+            picker.pick_from_world_ray(Vec3::Y * 5.0, Vec3::NEG_Y)
+        })
+        .remove(0);
+
+        let point = hit.camera_space_point.unwrap();
+        // cameras look down their local -Z
+        assert!(
+            point.abs_diff_eq(Vec3::new(0.0, 0.0, -5.0), 1e-4),
+            "{point}"
+        );
+    }
+
+    #[test]
+    fn over_the_plane_limit_nothing_is_picked() {
+        let mut app = headless_app();
+        app.world
+            .resource_mut::<PickingConfig>()
+            .max_planes_per_pick = Some(2);
+        spawn_camera(&mut app, Vec3::Y * 5.0, Vec3::ZERO);
+        let planes =
+            [0.0, 1.0, 2.0].map(|y| spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, y, 0.0)));
+        let pick = |app: &mut App| {
+            run(app, |picker: PlanePicker| {
+                picker.pick_from_world_ray(Vec3::Y * 5.0, Vec3::NEG_Y).len()
+            })
+        };
+        let warned = |app: &App| {
+            let config = app.world.resource::<PickingConfig>();
+            config.warned_plane_limit.load(Ordering::Relaxed)
+        };
+
+        // the warning is only logged when the flag is first raised
+        assert_eq!(pick(&mut app), 0);
+        assert!(warned(&app));
+        assert_eq!(pick(&mut app), 0);
+        assert!(warned(&app));
+
+        app.world.despawn(planes[0]);
+        assert_eq!(pick(&mut app), 2);
+        assert!(!warned(&app));
+    }
+
+    #[test]
+    fn reference_points_are_relative_to_the_frame() {
+        let mut app = headless_app();
+        let frame = Transform::from_xyz(3.0, 0.0, -2.0);
+        let frame = app.world.spawn(GlobalTransform::from(frame)).id();
+        app.world.resource_mut::<PickingConfig>().reference_frame = Some(frame);
+        spawn_camera(&mut app, Vec3::Y * 5.0, Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+
+        let hit = run(&mut app, |picker: PlanePicker| {
+            picker.pick_from_world_ray(Vec3::new(0.5, 5.0, 0.5), Vec3::NEG_Y)
+        })
+        .remove(0);
+
+        let point = hit.reference_point.unwrap();
+        assert!(
+            point.abs_diff_eq(Vec3::new(-2.5, 0.0, 2.5), 1e-5),
+            "{point}"
+        );
+    }
+
+    #[test]
+    fn points_at_a_depth_lie_on_the_cursor_ray() {
+        let mut app = headless_app();
+        let camera = spawn_camera(&mut app, Vec3::ZERO, Vec3::NEG_Z);
+        app.update();
+        let target = Vec3::new(1.0, 0.5, -4.0);
+        let position = screen_point(&mut app, camera, target);
+        move_cursor(&mut app, Some(position));
+
+        let points = run(&mut app, |picker: PlanePicker| {
+            [4.0, 8.0, 0.01].map(|depth| picker.cursor_point_at_depth(depth))
+        });
+        assert!(
+            points[0].unwrap().abs_diff_eq(target, 1e-3),
+            "{:?}",
+            points[0]
+        );
+        assert!(
+            points[1].unwrap().abs_diff_eq(target * 2.0, 1e-3),
+            "{:?}",
+            points[1]
+        );
+        // in front of the camera, but behind the near plane the ray starts on
+        assert_eq!(points[2], None);
+    }
+
+    #[test]
+    fn cursor_ndc_spans_the_viewport() {
+        let mut app = headless_app();
+        spawn_camera(&mut app, Vec3::Y * 5.0, Vec3::ZERO);
+        app.update();
+        let center = window_center(&mut app);
+        let mut ndc_at = |position: Vec2| {
+            move_cursor(&mut app, Some(position));
+            run(&mut app, |picker: PlanePicker| picker.cursor_ndc()).unwrap()
+        };
+
+        assert!(ndc_at(center).abs_diff_eq(Vec2::ZERO, 1e-5));
+        // pixels run down from the top left, NDC up from the bottom left
+        assert!(ndc_at(Vec2::ZERO).abs_diff_eq(Vec2::new(-1.0, 1.0), 1e-5));
+        assert!(ndc_at(center * 2.0).abs_diff_eq(Vec2::new(1.0, -1.0), 1e-5));
+    }
+}
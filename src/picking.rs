@@ -0,0 +1,2191 @@
+use std::{
+    cmp::Reverse,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use bevy::{
+    ecs::{query::WorldQuery, system::SystemParam},
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        primitives::{Aabb, Frustum},
+    },
+    window::{CursorGrabMode, WindowRef},
+};
+
+use crate::{
+    click::ClickCooldowns, color::material_color, plane_screen_rect, viewport_ray, AnalyticPlanes,
+    AnchorPick, BlendedHitEvent, CustomBounds, Face, Heightfield, LastMiss, MainCamera, Modifiers,
+    MyEllipse, MyPlane, PickFilter, PickMode, PickPriority, PickSettings, PickingConfig,
+    PlacementEvent, PlaneHit, PlaneHitEvent, PlaneMissEvent, PlanePicker, PressSubFrame, RayExt,
+    RayOrigin, SelectBy, TieBreak, ToleranceMode, UvTransform,
+};
+
+/// The closest plane under the cursor this frame, if any, for the
+/// [`IntersectPlanePlugin`](crate::IntersectPlanePlugin) instance picking planes marked `P`
+/// through cameras marked `C`.
+///
+/// A lighter-weight output than [`PlaneHitEvent`] for when you only care which plane is hovered.
+/// Hover is tested every frame, whether or not anything is clicked, and respects the same
+/// [`PickSettings`] as clicks (except [`PickSettings::mode`]: hover is always the
+/// [`PickMode::Closest`] hit). It's only written when the hovered plane changes, so
+/// `Res::is_changed` tells you when that happens.
+#[derive(Resource, Deref)]
+pub struct HoveredPlane<C = MainCamera, P = MyPlane>(
+    #[deref] pub Option<Entity>,
+    PhantomData<fn() -> (C, P)>,
+);
+
+impl<C, P> Default for HoveredPlane<C, P> {
+    fn default() -> Self {
+        Self(None, PhantomData)
+    }
+}
+
+/// Every plane under the cursor this frame, front to back, for the same instance as
+/// [`HoveredPlane`]: e.g. for a tooltip listing every layer under the cursor.
+///
+/// Only hits within a plane's bounds are listed, as in [`PickMode::All`], after the same filters
+/// and [`PickSettings::max_distance`] as clicks. Empty when nothing is hovered, and like
+/// [`HoveredPlane`] only written when it changes.
+#[derive(Resource, Deref)]
+pub struct HoveredPlanes<C = MainCamera, P = MyPlane>(
+    #[deref] pub Vec<Entity>,
+    PhantomData<fn() -> (C, P)>,
+);
+
+impl<C, P> Default for HoveredPlanes<C, P> {
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+/// Where the cursor is on the [`HoveredPlane`], updated every frame, for hover effects that follow
+/// the cursor.
+///
+/// `uv` is smoothed by [`PickSettings::hover_smoothing`] so it doesn't jitter with a shaky cursor
+/// or touch input; clicks always report the raw uv. Smoothing starts over whenever the hovered
+/// plane changes.
+#[derive(Resource)]
+pub struct HoverUv<C = MainCamera, P = MyPlane> {
+    /// The smoothed uv
+    pub uv: Option<Vec2>,
+    /// The uv under the cursor this frame
+    pub raw_uv: Option<Vec2>,
+    /// The whole hit under the cursor this frame, e.g. for its world position
+    pub hit: Option<PlaneHit>,
+    marker: PhantomData<fn() -> (C, P)>,
+}
+
+impl<C, P> Default for HoverUv<C, P> {
+    fn default() -> Self {
+        Self {
+            uv: None,
+            raw_uv: None,
+            hit: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// The components picking reads from each plane.
+///
+/// Only `GlobalTransform` and [`MyPlane`] are required, so a plane without a mesh (an invisible
+/// trigger zone, or game logic in a headless app) still picks. Everything else is optional.
+///
+/// Picking goes by the `GlobalTransform`, so planes parented to other entities are picked where
+/// they're drawn. It's propagated after `Update`, so a plane moved this frame is picked where it
+/// was at the end of the last one.
+#[derive(WorldQuery)]
+pub(crate) struct PickablePlane {
+    pub(crate) entity: Entity,
+    transform: &'static GlobalTransform,
+    plane: &'static MyPlane,
+    filter: Option<&'static PickFilter>,
+    priority: Option<&'static PickPriority>,
+    bounds_predicate: Option<&'static CustomBounds>,
+    heightfield: Option<&'static Heightfield>,
+    ellipse: Option<&'static MyEllipse>,
+    uv_transform: Option<&'static UvTransform>,
+    // only used for tie-breaking by render order and back face culling
+    pub(crate) material: Option<&'static Handle<StandardMaterial>>,
+    // computed for rendering, only used for frustum culling
+    bounds: Option<&'static Aabb>,
+}
+
+/// The components picking reads from the camera.
+#[derive(WorldQuery)]
+pub(crate) struct PickableCamera {
+    pub(crate) entity: Entity,
+    pub(crate) camera: &'static Camera,
+    pub(crate) transform: &'static GlobalTransform,
+    frustum: Option<&'static Frustum>,
+    // only used for the clip planes, which custom projections don't expose
+    projection: Option<&'static Projection>,
+}
+
+impl<'a> From<PickableCameraItem<'a>> for PickCamera<'a> {
+    fn from(item: PickableCameraItem<'a>) -> Self {
+        Self {
+            entity: item.entity,
+            camera: item.camera,
+            transform: item.transform,
+            frustum: item.frustum,
+            projection: item.projection,
+        }
+    }
+}
+
+/// The camera a pick is made through.
+#[derive(Clone, Copy)]
+pub(crate) struct PickCamera<'a> {
+    pub(crate) entity: Entity,
+    pub(crate) camera: &'a Camera,
+    pub(crate) transform: &'a GlobalTransform,
+    pub(crate) frustum: Option<&'a Frustum>,
+    pub(crate) projection: Option<&'a Projection>,
+}
+
+impl PickCamera<'_> {
+    /// How many viewport pixels apart `a` and `b` appear, if both can be projected.
+    fn pixel_distance(&self, a: Vec3, b: Vec3) -> Option<f32> {
+        let a = self.camera.world_to_viewport(self.transform, a)?;
+        let b = self.camera.world_to_viewport(self.transform, b)?;
+        Some(a.distance(b))
+    }
+
+    /// The world-space width of a viewport pixel at `hit`, or 0 if the hit can't be projected.
+    ///
+    /// Measured by casting a second ray a pixel over, so it works with any projection.
+    fn world_per_pixel(&self, hit: &PlaneHit) -> f32 {
+        self.camera
+            .world_to_viewport(self.transform, hit.world)
+            .and_then(|pixel| viewport_ray(self.camera, self.transform, pixel + Vec2::X))
+            .map_or(0.0, |ray| ray.get_point(hit.distance).distance(hit.world))
+    }
+
+    /// Whether `point` lies between the camera's near and far clip planes, i.e. its depth along
+    /// the camera's view direction is within them. Always true for a camera without a built-in
+    /// [`Projection`], whose clip planes aren't known.
+    fn within_clip_planes(&self, point: Vec3) -> bool {
+        let (near, far) = match self.projection {
+            Some(Projection::Perspective(projection)) => (projection.near, projection.far),
+            Some(Projection::Orthographic(projection)) => (projection.near, projection.far),
+            None => return true,
+        };
+        let depth = (point - self.transform.translation()).dot(self.transform.forward());
+        (near..=far).contains(&depth)
+    }
+}
+
+/// Every hit of `ray` on `planes` allowed by `settings`, front to back.
+///
+/// This ignores [`PickSettings::mode`], see [`apply_mode`].
+pub(crate) fn pick_planes<'a>(
+    settings: &PickSettings,
+    ray: Ray,
+    camera: PickCamera,
+    materials: Option<&Assets<StandardMaterial>>,
+    planes: impl Iterator<Item = PickablePlaneItem<'a>>,
+    analytic_planes: Option<&AnalyticPlanes>,
+) -> Vec<PlaneHit> {
+    let analytic_hits = analytic_planes
+        .into_iter()
+        .flat_map(|planes| planes.iter())
+        .filter_map(|&(origin, normal)| {
+            PlaneHit::compute_analytic(ray, camera.entity, origin, normal, settings.reference_up)
+        })
+        .filter(|hit| !(settings.cull_backfaces && hit.face == Face::Back))
+        .map(|hit| (hit, TieKey::default()));
+    let mut hits: Vec<(PlaneHit, TieKey)> = planes
+        .map(|item| (item.transform.compute_transform(), item))
+        .filter(|(_, item)| {
+            item.filter
+                .is_none_or(|filter| filter.accepts(settings.accept_mask))
+        })
+        // a plane entirely outside the view can't be under the cursor, so skip it before doing
+        // any ray maths. Note this also drops hits outside the bounds of such planes.
+        .filter(|(_, item)| {
+            let (true, Some(frustum), Some(aabb)) =
+                (settings.frustum_culling, camera.frustum, item.bounds)
+            else {
+                return true;
+            };
+            frustum.intersects_obb(aabb, &item.transform.compute_matrix(), true, true)
+        })
+        .filter(|(_, item)| {
+            let Some(min_size) = settings.min_screen_size_px else {
+                return true;
+            };
+            plane_screen_rect(camera.camera, camera.transform, item.plane, item.transform)
+                .is_none_or(|rect| rect.size().max_element() >= min_size)
+        })
+        .filter(|(transform, item)| {
+            !settings.obb_broadphase || ray_hits_obb(ray, transform, item.plane, settings.thickness)
+        })
+        .filter(|(_, item)| {
+            let Some(min_alpha) = settings.min_alpha else {
+                return true;
+            };
+            // planes whose material isn't known are picked
+            item.material
+                .zip(materials)
+                .and_then(|(handle, materials)| materials.get(handle))
+                .is_none_or(|material| material.base_color.a() >= min_alpha)
+        })
+        .filter_map(|(transform, item)| {
+            let mut hit =
+                PlaneHit::compute(ray, camera.entity, item.entity, &transform, item.plane)?;
+            if settings.cull_backfaces && hit.face == Face::Back {
+                // a material drawn from both sides can be picked from both sides
+                let material = item
+                    .material
+                    .zip(materials)
+                    .and_then(|(handle, materials)| materials.get(handle));
+                if material.is_none_or(|material| material.cull_mode.is_some()) {
+                    return None;
+                }
+            }
+            if settings.thickness > 0.0 {
+                hit = thicken(hit, ray, &transform, item.plane, settings.thickness);
+            }
+            if settings.shrink_margin != 0.0 {
+                let margin = settings.shrink_margin / item.plane.world_size(&transform);
+                hit.inside = hit.uv.cmpge(margin).all() && hit.uv.cmple(1.0 - margin).all();
+            }
+            if settings.pick_tolerance > 0.0 && !hit.inside {
+                let tolerance = match settings.tolerance_mode {
+                    ToleranceMode::WorldUnits => settings.pick_tolerance,
+                    ToleranceMode::Pixels => settings.pick_tolerance * camera.world_per_pixel(&hit),
+                };
+                let margin = tolerance / item.plane.world_size(&transform);
+                hit.inside = hit.uv.cmpge(-margin).all() && hit.uv.cmple(1.0 + margin).all();
+            }
+            if let Some(bounds) = item.bounds_predicate {
+                hit.inside = (bounds.0)(hit.uv);
+            }
+            if let Some(heightfield) = item.heightfield {
+                let height = (heightfield.0)(hit.uv);
+                hit.world += item.plane.front_normal(&transform) * height;
+                hit.local_mesh_point += hit.local_normal * height;
+            }
+            hit.priority = item.priority.map_or(0, |priority| priority.0);
+            if settings.mirror_back_uv && hit.face == Face::Back {
+                hit.uv.x = 1.0 - hit.uv.x;
+            }
+            let material = item
+                .material
+                .zip(materials)
+                .and_then(|(handle, materials)| materials.get(handle));
+            let key = TieKey::new(settings, &hit, material);
+            let (hit, mut key) =
+                if settings.cursor_radius > 0.0 && !hit.inside && item.bounds_predicate.is_none() {
+                    snap_to_edge(settings, ray, camera, (item.plane, &transform), hit, key)
+                } else {
+                    (hit, key)
+                };
+            if let Some((original, _)) = &mut key.near_miss {
+                *original = report_uv(settings, &item, *original);
+            }
+            Some((report_uv(settings, &item, hit), key))
+        })
+        .chain(analytic_hits)
+        .filter(|(hit, _)| settings.max_distance.is_none_or(|max| hit.distance <= max))
+        .filter(|(hit, _)| !settings.respect_clip_planes || camera.within_clip_planes(hit.world))
+        .filter(|(hit, _)| {
+            settings
+                .desired_normal
+                .is_none_or(|desired| desired.accepts(hit.normal))
+        })
+        .collect();
+    settle_near_misses(&mut hits);
+    for (hit, _) in &mut hits {
+        *hit = hit.with_reference_up(settings.reference_up);
+        hit.unit_scale = settings.unit_scale;
+    }
+    order_hits(settings, hits)
+}
+
+/// `hits` front to back, then by the tie-break rule, then by lowest entity index so the order
+/// never depends on query order, with those within [`PickSettings::seam_epsilon`] merged.
+pub(crate) fn order_hits(
+    settings: &PickSettings,
+    mut hits: Vec<(PlaneHit, TieKey)>,
+) -> Vec<PlaneHit> {
+    hits.sort_by(|(a, a_key), (b, b_key)| {
+        a.distance
+            .total_cmp(&b.distance)
+            .then_with(|| b_key.priority.cmp(&a_key.priority))
+            .then_with(|| b_key.depth_bias.total_cmp(&a_key.depth_bias))
+            .then_with(|| a.entity.index().cmp(&b.entity.index()))
+    });
+    let hits = hits.into_iter().map(|(hit, _)| hit).collect();
+    match settings.seam_epsilon {
+        Some(epsilon) => merge_seams(hits, epsilon),
+        None => hits,
+    }
+}
+
+/// Merge the front-to-back `hits` within `epsilon` of each other into the best of them, see
+/// [`PickSettings::seam_epsilon`], keeping them front to back.
+fn merge_seams(hits: Vec<PlaneHit>, epsilon: f32) -> Vec<PlaneHit> {
+    let rank = |hit: &PlaneHit| (hit.inside, hit.priority, Reverse(hit.entity.index()));
+    let mut merged: Vec<PlaneHit> = Vec::with_capacity(hits.len());
+    for hit in hits {
+        match merged
+            .iter_mut()
+            .find(|kept| kept.world.distance(hit.world) <= epsilon)
+        {
+            Some(kept) if rank(&hit) > rank(kept) => *kept = hit,
+            Some(_) => {}
+            None => merged.push(hit),
+        }
+    }
+    merged
+}
+
+/// What equidistant hits are ordered by, higher first, before falling back to entity index.
+#[derive(Default)]
+pub(crate) struct TieKey {
+    priority: i32,
+    depth_bias: f32,
+    /// For a hit snapped onto the plane's edge by [`PickSettings::cursor_radius`], the hit as it
+    /// was and how many pixels it was moved
+    near_miss: Option<(PlaneHit, f32)>,
+}
+
+impl TieKey {
+    /// The key [`PickSettings::tie_break`] orders `hit` by, on a plane drawn with `material`.
+    pub(crate) fn new(
+        settings: &PickSettings,
+        hit: &PlaneHit,
+        material: Option<&StandardMaterial>,
+    ) -> Self {
+        match settings.tie_break {
+            TieBreak::EntityIndex => Self::default(),
+            TieBreak::RenderOrder => Self {
+                depth_bias: material.map_or(0.0, |material| material.depth_bias),
+                ..default()
+            },
+            TieBreak::Priority => Self {
+                priority: hit.priority,
+                ..default()
+            },
+        }
+    }
+}
+
+/// If the point of the plane's rectangle nearest `hit` is within
+/// [`PickSettings::cursor_radius`] pixels of it, move `hit` there and count it as inside, keeping
+/// the original in the key for [`settle_near_misses`].
+fn snap_to_edge(
+    settings: &PickSettings,
+    ray: Ray,
+    camera: PickCamera,
+    (plane, transform): (&MyPlane, &Transform),
+    hit: PlaneHit,
+    mut key: TieKey,
+) -> (PlaneHit, TieKey) {
+    // `hit.uv` may be mirrored, but clamping it works the same either way
+    let uv = hit.uv.clamp(Vec2::ZERO, Vec2::ONE);
+    let local_uv = if settings.mirror_back_uv && hit.face == Face::Back {
+        Vec2::new(1.0 - uv.x, uv.y)
+    } else {
+        uv
+    };
+    let world = plane.point_at_uv(transform, local_uv);
+    let Some(pixels) = camera
+        .pixel_distance(hit.world, world)
+        .filter(|&pixels| pixels <= settings.cursor_radius)
+    else {
+        return (hit, key);
+    };
+    key.near_miss = Some((hit, pixels));
+    let snapped = PlaneHit {
+        world,
+        local_mesh_point: MyPlane::to_local(&plane.oriented(transform), world),
+        axis_distances: plane.axis_distances(transform, world),
+        uv,
+        inside: true,
+        distance: ray.origin.distance(world),
+        ..hit
+    };
+    (snapped, key)
+}
+
+/// Orient `hit.uv` as [`PickSettings::uv_origin`] and [`PickSettings::flip_v`] ask, remap it
+/// through the plane's [`UvTransform`], if any, and fill in `hit.coords` from it, and
+/// `hit.ellipse` for a [`MyEllipse`].
+fn report_uv(settings: &PickSettings, item: &PickablePlaneItem, mut hit: PlaneHit) -> PlaneHit {
+    let size = item.plane.world_size(&item.transform.compute_transform());
+    hit.uv = settings.uv_origin.from_top_left(hit.uv);
+    if settings.flip_v {
+        hit.uv.y = 1.0 - hit.uv.y;
+    }
+    if let Some(uv_transform) = item.uv_transform {
+        hit.uv = uv_transform.apply(hit.uv);
+    }
+    hit.coords = settings.coord_space.convert_uv(hit.uv, size);
+    hit.ellipse = item
+        .ellipse
+        .map(|ellipse| ellipse.coords(hit.local_mesh_point));
+    hit
+}
+
+/// Only keep the snapped near miss closest to the cursor, and only if the ray isn't exactly inside
+/// any plane; put the others back as they were.
+fn settle_near_misses(hits: &mut [(PlaneHit, TieKey)]) {
+    let exact = hits
+        .iter()
+        .any(|(hit, key)| hit.inside && key.near_miss.is_none());
+    let nearest = hits
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (_, key))| key.near_miss.map(|(_, pixels)| (i, pixels)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(i, _)| i)
+        .filter(|_| !exact);
+    for (i, (hit, key)) in hits.iter_mut().enumerate() {
+        if Some(i) == nearest {
+            continue;
+        }
+        if let Some((original, _)) = key.near_miss.take() {
+            *hit = original;
+        }
+    }
+}
+
+/// Whether `ray` could hit any of `planes` inside its bounds, as a cheap yes or no before doing
+/// more expensive work per hit. Stops at the first plane whose bounding box the ray crosses, using
+/// the same test as [`PickSettings::obb_broadphase`], without computing any hit.
+///
+/// Planes behind the ray's origin can't be hit. It's conservative, so `true` doesn't guarantee a
+/// pick, e.g. for a ray grazing a plane edge-on, which picking rejects as parallel, or when the
+/// pick settings filter the plane out.
+pub fn any_plane_in_ray<'a>(
+    ray: Ray,
+    planes: impl IntoIterator<Item = (&'a Transform, &'a MyPlane)>,
+) -> bool {
+    planes
+        .into_iter()
+        .any(|(transform, plane)| ray_hits_obb(ray, transform, plane, 0.0))
+}
+
+/// A quick test of `ray` against the plane's oriented bounding box: a thin box with the plane's
+/// rotation and half-extents, at least as thick as [`PickSettings::thickness`]. This is a superset
+/// of the inside hits picking finds.
+fn ray_hits_obb(ray: Ray, transform: &Transform, plane: &MyPlane, thickness: f32) -> bool {
+    // a little thickness so rays are never rejected by floating point error at the surface
+    const MIN_HALF_THICKNESS: f32 = 1e-4;
+    slab_distance(ray, transform, plane, thickness.max(MIN_HALF_THICKNESS)).is_some()
+}
+
+/// Widen `hit` to the band `thickness` either side of the plane: if the ray crosses the band
+/// within the plane bounds, the hit is inside and moved to where the ray enters the band.
+fn thicken(
+    hit: PlaneHit,
+    ray: Ray,
+    transform: &Transform,
+    plane: &MyPlane,
+    thickness: f32,
+) -> PlaneHit {
+    let Some(distance) = slab_distance(ray, transform, plane, thickness) else {
+        return hit;
+    };
+    let world = ray.get_point(distance);
+    let local_mesh_point = MyPlane::to_local(&plane.oriented(transform), world);
+    let uv = plane.local_uv(local_mesh_point);
+    PlaneHit {
+        world,
+        local_mesh_point,
+        axis_distances: plane.axis_distances(transform, world),
+        uv,
+        coords: uv,
+        inside: true,
+        distance,
+        ..hit
+    }
+}
+
+/// The distance along `ray` to where it enters the box `half_thickness` either side of the plane
+/// and within its bounds, or 0 if it starts inside the box.
+fn slab_distance(
+    ray: Ray,
+    transform: &Transform,
+    plane: &MyPlane,
+    half_thickness: f32,
+) -> Option<f32> {
+    let oriented = plane.oriented(transform);
+    let inverse_rotation = oriented.rotation.inverse();
+    let local_ray = Ray {
+        origin: inverse_rotation * (ray.origin - transform.translation),
+        direction: inverse_rotation * ray.direction,
+    };
+    // the box is in world units, only turned to line up with the plane
+    let half_size = plane.world_size(transform) * 0.5;
+    let half_extents = Vec3::new(half_size.x, half_thickness, half_size.y);
+    let obb = Aabb::from_min_max(-half_extents, half_extents);
+    local_ray.intersect_aabb(&obb)
+}
+
+/// Narrow the front-to-back `hits` of `ray` down to what `mode` reports.
+pub(crate) fn apply_mode(
+    mode: PickMode,
+    settings: &PickSettings,
+    ray: Ray,
+    hits: &mut Vec<PlaneHit>,
+) {
+    if mode == PickMode::Closest {
+        // the highest priority wins regardless of distance, then the front-most hit, with planes
+        // seen edge-on counted as further away by up to `facing_weight` times their distance, or
+        // the hit nearest the chosen point
+        let score = |hit: &PlaneHit| match settings.select_by {
+            SelectBy::RayDistance => {
+                let facing = ray.direction.normalize_or_zero().dot(hit.normal).abs();
+                hit.distance * (1.0 + settings.facing_weight * (1.0 - facing))
+            }
+            SelectBy::DistanceToPoint(point) => hit.world.distance(point),
+        };
+        let closest = hits
+            .iter()
+            .filter(|hit| hit.inside)
+            .min_by(|a, b| {
+                b.priority
+                    .cmp(&a.priority)
+                    .then_with(|| score(a).total_cmp(&score(b)))
+            })
+            .copied();
+        hits.clear();
+        hits.extend(closest);
+    } else if let Some(max) = settings.max_hits {
+        // already front to back
+        hits.truncate(max);
+    }
+}
+
+/// The events a click sends.
+#[derive(SystemParam)]
+pub(crate) struct PickEvents<'w> {
+    hits: EventWriter<'w, PlaneHitEvent>,
+    blended: EventWriter<'w, BlendedHitEvent>,
+    placement: EventWriter<'w, PlacementEvent>,
+    miss: EventWriter<'w, PlaneMissEvent>,
+    last_miss: ResMut<'w, LastMiss>,
+}
+
+/// What a click reads from input.
+#[derive(SystemParam)]
+pub(crate) struct ClickInput<'w> {
+    mouse: Res<'w, Input<MouseButton>>,
+    // optional so picking works without keyboard input
+    keys: Option<Res<'w, Input<KeyCode>>>,
+    // optional so picking works without touch input
+    touches: Option<Res<'w, Touches>>,
+    sub_frame: Res<'w, PressSubFrame>,
+}
+
+/// What a click, press or drag picks from: the cursor, for the pick button, or a touch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Pointer {
+    Cursor,
+    /// Where the touch is in the primary window, in logical pixels
+    Touch(Vec2),
+}
+
+impl ClickInput<'_> {
+    /// A click this frame: a press of the pick button, or a tap, a touch lifted after moving no
+    /// more than [`PickSettings::tap_threshold_px`] from where it touched down. Touches that
+    /// moved further are drags, and don't click.
+    pub(crate) fn click(&self, settings: &PickSettings) -> Option<Pointer> {
+        if self.mouse.just_pressed(settings.pick_button) {
+            return Some(Pointer::Cursor);
+        }
+        self.touches
+            .as_deref()?
+            .iter_just_released()
+            .find(|touch| touch.distance().length() <= settings.tap_threshold_px)
+            .map(|touch| Pointer::Touch(touch.position()))
+    }
+
+    /// The start of a press this frame: the pick button going down, or a touch touching down.
+    pub(crate) fn press(&self, settings: &PickSettings) -> Option<Pointer> {
+        if self.mouse.just_pressed(settings.pick_button) {
+            return Some(Pointer::Cursor);
+        }
+        self.touches
+            .as_deref()?
+            .iter_just_pressed()
+            .next()
+            .map(|touch| Pointer::Touch(touch.position()))
+    }
+
+    /// What's pressing while the pick button is held, or a touch is down.
+    pub(crate) fn held(&self, settings: &PickSettings) -> Option<Pointer> {
+        if self.mouse.pressed(settings.pick_button) {
+            return Some(Pointer::Cursor);
+        }
+        self.touches
+            .as_deref()?
+            .iter()
+            .next()
+            .map(|touch| Pointer::Touch(touch.position()))
+    }
+
+    /// Tag a hit of this frame's click with the modifiers held and when the click happened.
+    pub(crate) fn tag(&self, hit: &mut PlaneHit) {
+        hit.modifiers = self
+            .keys
+            .as_deref()
+            .map(Modifiers::from_keys)
+            .unwrap_or_default();
+        if let Some(sub_frame) = self.sub_frame.0 {
+            hit.sub_frame = sub_frame.clamp(0.0, 1.0);
+        }
+    }
+}
+
+pub(crate) fn my_cursor_system<C: Component, P: Component>(
+    // the camera, window, planes and settings of this instance
+    picker: PlanePicker<C, P>,
+    // only needed to weight blended hits by transparency, and to sample colors
+    q_material: Query<&Handle<StandardMaterial>>,
+    // optional so picking works without the PBR plugin, like `PlanePicker`
+    materials: Option<Res<Assets<StandardMaterial>>>,
+    images: Option<Res<Assets<Image>>>,
+    input: ClickInput,
+    mut cooldowns: ClickCooldowns,
+    mut events: PickEvents,
+) {
+    let config = picker.config();
+    let Some(pointer) = input.click(config) else {
+        return;
+    };
+    // assuming there is exactly one camera entity with this instance's marker
+    let (Some(camera_entity), Some(ray)) = (picker.camera(), picker.pointer_ray(pointer)) else {
+        return;
+    };
+    let ndc = picker.pointer_ndc(pointer);
+
+    let mut hits = picker.pick_ray(ray);
+    picker.add_shape_hits(&mut hits);
+    apply_mode(config.mode, config, ray, &mut hits);
+    // before the cooldowns, so a plane that's cooling down still counts as clicked on
+    let missed = !hits.iter().any(|hit| hit.inside);
+    if !missed {
+        if events.last_miss.is_some() {
+            events.last_miss.0 = None;
+        }
+    } else {
+        events.last_miss.0 = Some(ray);
+        events.miss.send(PlaneMissEvent {
+            camera: camera_entity,
+            ray,
+        });
+    }
+    if !config.emit_outside_hits {
+        hits.retain(|hit| hit.inside);
+    }
+    hits.retain(|hit| cooldowns.allow(hit.entity));
+    let material = |entity| {
+        q_material
+            .get(entity)
+            .ok()
+            .zip(materials.as_deref())
+            .and_then(|(handle, materials)| materials.get(handle))
+    };
+    picker.set_cursor_ndc(&mut hits, ndc);
+    for hit in &mut hits {
+        input.tag(hit);
+    }
+    if config.sample_color {
+        for hit in hits.iter_mut().filter(|hit| hit.inside) {
+            hit.sampled_color = material(hit.entity)
+                .map(|material| material_color(material, images.as_deref(), hit.uv));
+        }
+    }
+
+    for hit in &hits {
+        if config.log_picks {
+            // planes with a `Name` are logged by it, as well as their entity
+            let name = picker.name(hit.entity);
+            let label = match name {
+                Some(name) => format!("{name} ({:?})", hit.entity),
+                None => format!("{:?}", hit.entity),
+            };
+            if hit.inside {
+                info!(
+                    entity = ?hit.entity,
+                    name,
+                    uv = %hit.uv,
+                    inside = hit.inside,
+                    "{label}: hit at {x:.2},{y:.2} within surface",
+                    x = hit.uv.x,
+                    y = hit.uv.y
+                );
+            } else {
+                debug!(
+                    entity = ?hit.entity,
+                    name,
+                    uv = %hit.uv,
+                    inside = hit.inside,
+                    "{label}: hit at {x:.2},{y:.2} outside surface",
+                    x = hit.uv.x,
+                    y = hit.uv.y
+                );
+            }
+        }
+        events.hits.send(picker.hit_event(*hit));
+    }
+
+    if let (PickMode::All, Some(layers)) = (config.mode, config.blend_layers) {
+        let alpha = |entity| material(entity).map_or(1.0, |material| material.base_color.a());
+        let layers = hits
+            .iter()
+            .filter(|hit| hit.inside)
+            .take(layers)
+            .map(|hit| (*hit, alpha(hit.entity)));
+        if let Some(blended) = BlendedHitEvent::from_layers(camera_entity, layers) {
+            events.blended.send(blended);
+        }
+    }
+
+    if let Some(band) = config.placement_band {
+        let placement = PlacementEvent::from_hits(camera_entity, ray, &picker.pick_ray(ray), band);
+        if let Some(placement) = placement {
+            events.placement.send(placement);
+        }
+    }
+}
+
+/// A hover pick spread over several frames by [`PickSettings::budget_per_frame`].
+#[derive(Default)]
+pub(crate) struct HoverPass {
+    /// The cursor ray the pass started with, `None` between passes
+    ray: Option<Ray>,
+    /// How many planes the pass has tested so far
+    tested: usize,
+    /// The inside hits so far
+    hits: Vec<PlaneHit>,
+}
+
+/// The hits of the first of `substeps` evenly spaced steps from `from` to `to`, in viewport
+/// pixels, that's inside a plane other than `ignored`, or of the ray through `to` if none are, see
+/// [`PickSettings::hover_substeps`].
+fn swept_hits<C: Component, P: Component>(
+    picker: &PlanePicker<C, P>,
+    from: Vec2,
+    to: Vec2,
+    substeps: u32,
+    ignored: Option<Entity>,
+) -> (Option<Ray>, Vec<PlaneHit>) {
+    // the last step is `to` itself
+    for step in 1..substeps {
+        let Some(ray) = picker.viewport_ray(from.lerp(to, step as f32 / substeps as f32)) else {
+            continue;
+        };
+        let mut hits = picker.pick_ray(ray);
+        hits.retain(|hit| Some(hit.entity) != ignored);
+        if hits.iter().any(|hit| hit.inside) {
+            return (Some(ray), hits);
+        }
+    }
+    let ray = picker.viewport_ray(to);
+    (ray, ray.map(|ray| picker.pick_ray(ray)).unwrap_or_default())
+}
+
+/// A run condition that's true on frames where the cursor moved, or a camera marked `C` moved, so
+/// the cursor may be over something else. For skipping cursor-driven work in idle scenes: the
+/// plugin uses it for hover with [`PickSettings::skip_idle_hover`].
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{cursor_or_camera_changed, MainCamera};
+/// # fn update_tooltip() {}
+/// App::new().add_systems(
+///     Update,
+///     update_tooltip.run_if(cursor_or_camera_changed::<MainCamera>),
+/// );
+/// ```
+pub fn cursor_or_camera_changed<C: Component>(
+    mut cursor_moved: EventReader<CursorMoved>,
+    q_camera: Query<(), (With<C>, Changed<GlobalTransform>)>,
+) -> bool {
+    // read every event, so they aren't seen again next frame
+    cursor_moved.iter().count() > 0 || !q_camera.is_empty()
+}
+
+/// A run condition that's true on frames where a plane marked `P` moved, so it may have moved
+/// under a still cursor, e.g. a moving platform. Goes with
+/// [`cursor_or_camera_changed`]: the plugin uses both for hover with
+/// [`PickSettings::skip_idle_hover`].
+///
+/// It watches `GlobalTransform`, which is what picking reads, so planes moved by their parents
+/// count too.
+pub fn planes_changed<P: Component>(
+    q_plane: Query<(), (With<P>, Changed<GlobalTransform>)>,
+) -> bool {
+    !q_plane.is_empty()
+}
+
+pub(crate) fn skip_idle_hover<C: Component, P: Component>(
+    config: Res<PickingConfig<C, P>>,
+) -> bool {
+    config.skip_idle_hover
+}
+
+pub(crate) fn update_hovered_plane<C: Component, P: Component>(
+    picker: PlanePicker<C, P>,
+    mut hovered: ResMut<HoveredPlane<C, P>>,
+    mut hovered_all: ResMut<HoveredPlanes<C, P>>,
+    mut hover_uv: ResMut<HoverUv<C, P>>,
+    mut pass: Local<HoverPass>,
+    mut last_cursor: Local<Option<Vec2>>,
+    anchor: Res<AnchorPick<C, P>>,
+) {
+    let ignored = anchor
+        .anchor
+        .filter(|_| picker.config().hover_ignores_dragged)
+        .map(|hit| hit.entity);
+    let (ray, mut hits) = match picker.config().budget_per_frame {
+        Some(budget) => {
+            let budget = budget.max(1);
+            let Some(ray) = pass.ray.or_else(|| picker.cursor_ray()) else {
+                // nothing to pick, so there's no point finishing a pass
+                *pass = HoverPass::default();
+                return;
+            };
+            let (hits, tested) = picker.pick_ray_chunk(ray, pass.tested, budget);
+            pass.hits.extend(hits.into_iter().filter(|hit| hit.inside));
+            pass.tested += tested;
+            if tested == budget {
+                pass.ray = Some(ray);
+                return;
+            }
+            let hits = std::mem::take(&mut pass.hits);
+            *pass = HoverPass::default();
+            // the chunks are each in order, but not with each other
+            (Some(ray), picker.order_hits(hits))
+        }
+        None => {
+            let cursor = picker.cursor_position();
+            let previous = std::mem::replace(&mut *last_cursor, cursor);
+            match (previous, cursor) {
+                (Some(from), Some(to)) if picker.config().hover_substeps > 1 && from != to => {
+                    swept_hits(&picker, from, to, picker.config().hover_substeps, ignored)
+                }
+                _ => {
+                    let ray = picker.cursor_ray();
+                    (ray, ray.map(|ray| picker.pick_ray(ray)).unwrap_or_default())
+                }
+            }
+        }
+    };
+    hits.retain(|hit| Some(hit.entity) != ignored);
+    let hovered_entities = hits
+        .iter()
+        .filter(|hit| hit.inside)
+        .map(|hit| hit.entity)
+        .collect::<Vec<_>>();
+    if hovered_all.0 != hovered_entities {
+        hovered_all.0 = hovered_entities;
+    }
+
+    let hit = ray.and_then(|ray| {
+        apply_mode(PickMode::Closest, picker.config(), ray, &mut hits);
+        let mut hit = hits.pop()?;
+        hit.cursor_ndc = picker.cursor_ndc();
+        Some(hit)
+    });
+    let hovered_entity = hit.map(|hit| hit.entity);
+    let raw_uv = hit.map(|hit| hit.uv);
+    hover_uv.uv = match (hover_uv.uv, raw_uv) {
+        (Some(smoothed), Some(raw)) if hovered.0 == hovered_entity => {
+            Some(raw.lerp(smoothed, picker.config().hover_smoothing))
+        }
+        _ => raw_uv,
+    };
+    hover_uv.raw_uv = raw_uv;
+    hover_uv.hit = hit;
+    // only write on change, so change detection means "the hovered plane changed"
+    if hovered.0 != hovered_entity {
+        hovered.0 = hovered_entity;
+    }
+}
+
+/// The ray from the camera through the cursor, if the cursor is in the window and the camera
+/// renders to it. A camera rendering to an image has no cursor, see
+/// [`PlanePicker::pick_at_pixel`].
+///
+/// While the cursor is locked, as in a first-person game, the window stops updating its position,
+/// so with [`PickSettings::locked_cursor_center`] the ray goes through the middle of the viewport
+/// instead, like a crosshair. There's no ray while the cursor is outside the
+/// [`PickSettings::pick_region`].
+///
+/// All cursor picking rays come from here. It only relies on the camera's projection matrix, so
+/// nothing downstream may assume a perspective (or orthographic) projection.
+///
+/// Building the ray can fail even with the cursor in the window, e.g. for a degenerate
+/// projection or before the camera's viewport is known, in which case nothing is picked. That's
+/// logged once, at debug level.
+pub(crate) fn cursor_ray(
+    window: (Entity, &Window),
+    primary_window: Option<Entity>,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    settings: &PickSettings,
+) -> Option<Ray> {
+    static LOGGED_FAILURE: AtomicBool = AtomicBool::new(false);
+
+    let cursor = cursor_position(window, primary_window, camera, settings)?;
+    let ray = viewport_ray(camera, camera_transform, cursor);
+    if ray.is_none() && !LOGGED_FAILURE.swap(true, Ordering::Relaxed) {
+        debug!("couldn't build a picking ray through the cursor at {cursor}, so nothing is picked");
+    }
+    ray
+}
+
+/// `ray`, built by [`viewport_ray`] from the near plane, moved to start from `origin`, keeping its
+/// direction. `q_pivot` looks up a [`RayOrigin::Entity`], with no ray if that fails.
+pub(crate) fn with_origin(
+    ray: Ray,
+    camera_transform: &GlobalTransform,
+    origin: RayOrigin,
+    q_pivot: &Query<&GlobalTransform>,
+) -> Option<Ray> {
+    let from = |origin| Ray {
+        origin,
+        direction: ray.direction,
+    };
+    match origin {
+        RayOrigin::NearPlane => Some(ray),
+        RayOrigin::CameraOrigin => {
+            let forward = camera_transform.forward();
+            let along = ray.direction.dot(forward);
+            if along.abs() <= f32::EPSILON {
+                return Some(ray);
+            }
+            // moved back along itself
+            let depth = (ray.origin - camera_transform.translation()).dot(forward);
+            Some(from(ray.origin - ray.direction * (depth / along)))
+        }
+        RayOrigin::Point(point) => Some(from(point)),
+        RayOrigin::Entity(entity) => {
+            let pivot = q_pivot.get(entity).ok()?;
+            Some(from(pivot.translation()))
+        }
+    }
+}
+
+/// Where the cursor is in the camera's viewport, in logical pixels, as in [`cursor_ray`].
+/// `primary_window` is the entity with [`PrimaryWindow`], if any.
+///
+/// [`PrimaryWindow`]: bevy::window::PrimaryWindow
+pub(crate) fn cursor_position(
+    (window_entity, window): (Entity, &Window),
+    primary_window: Option<Entity>,
+    camera: &Camera,
+    settings: &PickSettings,
+) -> Option<Vec2> {
+    if !renders_to_window(camera, window_entity, primary_window) {
+        return None;
+    }
+
+    let cursor =
+        if settings.locked_cursor_center && window.cursor.grab_mode == CursorGrabMode::Locked {
+            camera.logical_viewport_size()? * 0.5
+        } else {
+            window.cursor_position()?
+        };
+    settings
+        .pick_region
+        .is_none_or(|region| region.contains(cursor))
+        .then_some(cursor)
+}
+
+/// Where a touch at `position` in the primary window is in the camera's viewport, in logical
+/// pixels, under the same conditions as [`cursor_position`], bar the cursor being locked.
+pub(crate) fn touch_position(
+    primary_window: Entity,
+    camera: &Camera,
+    settings: &PickSettings,
+    position: Vec2,
+) -> Option<Vec2> {
+    if !renders_to_window(camera, primary_window, Some(primary_window)) {
+        return None;
+    }
+    settings
+        .pick_region
+        .is_none_or(|region| region.contains(position))
+        .then_some(position)
+}
+
+/// Whether `camera` draws to the window `window_entity`, where `primary_window` is the entity
+/// with [`PrimaryWindow`](bevy::window::PrimaryWindow), which cameras targeting
+/// [`WindowRef::Primary`] draw to.
+fn renders_to_window(
+    camera: &Camera,
+    window_entity: Entity,
+    primary_window: Option<Entity>,
+) -> bool {
+    match camera.target {
+        RenderTarget::Window(WindowRef::Primary) => primary_window == Some(window_entity),
+        RenderTarget::Window(WindowRef::Entity(entity)) => entity == window_entity,
+        RenderTarget::Image(_) | RenderTarget::TextureView(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use bevy::{
+        input::{touch::TouchPhase, ButtonState},
+        render::camera::{camera_system, CameraProjection, Viewport},
+    };
+
+    use super::*;
+    use crate::{
+        test_util::{
+            app_with, click_at, events, headless_app, move_cursor, primary_window, run,
+            screen_point, send_button, send_key, send_touch, spawn_camera, spawn_plane,
+            window_center,
+        },
+        Corner, DesiredNormal, FocusPlane, HitMarker, IntersectPlanePlugin, RayExt,
+    };
+
+    fn hovered(app: &App) -> Option<Entity> {
+        **app.world.resource::<HoveredPlane>()
+    }
+
+    #[test]
+    fn hovered_plane_follows_the_cursor() {
+        let mut app = app_with(IntersectPlanePlugin::default().max_distance(Some(20.0)));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let left = spawn_plane(&mut app, 2.0, Transform::from_xyz(-1.5, 0.0, 0.0));
+        let right = spawn_plane(&mut app, 2.0, Transform::from_xyz(1.5, 0.0, 0.0));
+        // further than `max_distance` from the camera
+        spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, -20.0, 3.0));
+        let hidden = spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 0.0, -3.0));
+        app.world.entity_mut(hidden).insert(PickFilter(0));
+        app.update();
+        assert_eq!(hovered(&app), None);
+
+        for (point, expected) in [
+            (Vec3::new(-1.5, 0.0, 0.0), Some(left)),
+            (Vec3::new(1.5, 0.0, 0.0), Some(right)),
+            (Vec3::new(0.0, -20.0, 3.0), None),
+            (Vec3::new(0.0, 0.0, -3.0), None),
+            (Vec3::new(0.0, 0.0, 0.0), None),
+        ] {
+            let position = screen_point(&mut app, camera, point);
+            move_cursor(&mut app, Some(position));
+            app.update();
+            assert_eq!(hovered(&app), expected, "cursor over {point}");
+        }
+        move_cursor(&mut app, None);
+        app.update();
+        assert_eq!(hovered(&app), None);
+    }
+
+    #[test]
+    fn outside_hits_are_dropped_unless_emitted() {
+        for emit in [true, false] {
+            let mut app = app_with(IntersectPlanePlugin::default().emit_outside_hits(emit));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 1.0, Transform::IDENTITY);
+            app.update();
+            let outside = screen_point(&mut app, camera, Vec3::new(3.0, 0.0, 0.0));
+            click_at(&mut app, outside);
+
+            let hits = events::<PlaneHitEvent>(&app);
+            let markers = app.world.query::<&HitMarker>().iter(&app.world).count();
+            if emit {
+                assert_eq!(hits.len(), 1);
+                assert!(!hits[0].hit.inside);
+                assert_eq!(markers, 1);
+            } else {
+                assert!(hits.is_empty());
+                assert_eq!(markers, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_planes_pick_where_they_are_drawn() {
+        let mut app = app_with(IntersectPlanePlugin::default().emit_outside_hits(true));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        // drawn 6 wide along X and 2 along Z
+        let scaled = Transform::from_scale(Vec3::new(3.0, 1.0, 1.0));
+        spawn_plane(&mut app, 2.0, scaled);
+        app.update();
+        // past the unscaled plane's edge, but on the scaled one
+        let on_stretch = screen_point(&mut app, camera, Vec3::new(2.5, 0.0, 0.0));
+        click_at(&mut app, on_stretch);
+        let hits = events::<PlaneHitEvent>(&app);
+        assert!(hits[0].hit.inside);
+        assert!((hits[0].hit.uv.x - (2.5 / 6.0 + 0.5)).abs() < 1e-3);
+        // in the mesh's own units, so within its size of 2
+        assert!((hits[0].hit.local_mesh_point.x - 2.5 / 3.0).abs() < 1e-3);
+
+        // the Z extent isn't stretched
+        let past_edge = screen_point(&mut app, camera, Vec3::new(0.0, 0.0, 1.5));
+        click_at(&mut app, past_edge);
+        assert!(!events::<PlaneHitEvent>(&app).last().unwrap().hit.inside);
+    }
+
+    #[test]
+    fn secondary_window_picks_only_what_its_camera_sees() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        let secondary = app.world.spawn(Window::default()).id();
+        // the picking camera draws one plane to the second window, away from the other plane
+        let camera = spawn_camera(&mut app, Vec3::new(20.0, 10.0, 0.1), Vec3::X * 20.0);
+        app.world.get_mut::<Camera>(camera).unwrap().target =
+            RenderTarget::Window(WindowRef::Entity(secondary));
+        let shown = spawn_plane(&mut app, 2.0, Transform::from_xyz(20.0, 0.0, 0.0));
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.world.resource_mut::<PickingConfig>().window = Some(secondary);
+        app.update();
+
+        let center = window_center(&mut app);
+        app.world
+            .get_mut::<Window>(secondary)
+            .unwrap()
+            .set_cursor_position(Some(center));
+        click_at(&mut app, center);
+        let hits: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .filter(|event| event.hit.inside)
+            .map(|event| event.hit.entity)
+            .collect();
+        assert_eq!(hits, [shown]);
+
+        // a camera drawing to the primary window doesn't pick with the second window's cursor
+        app.world.get_mut::<Camera>(camera).unwrap().target =
+            RenderTarget::Window(WindowRef::Primary);
+        app.update();
+        app.update();
+        click_at(&mut app, center);
+        assert!(events::<PlaneHitEvent>(&app).is_empty());
+    }
+
+    #[test]
+    fn planes_straddling_the_frustum_edge_arent_culled() {
+        let mut app = app_with(IntersectPlanePlugin::default().frustum_culling(true));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        // 4 wide, from x = 5.5 to 9.5, across the right edge of the view at about x = 7.4
+        let straddling = spawn_plane(&mut app, 4.0, Transform::from_xyz(7.5, 0.0, 0.0));
+        let beyond = spawn_plane(&mut app, 4.0, Transform::from_xyz(20.0, 0.0, 0.0));
+        let local_bounds = Aabb::from_min_max(Vec3::new(-2.0, 0.0, -2.0), Vec3::new(2.0, 0.0, 2.0));
+        for plane in [straddling, beyond] {
+            app.world.entity_mut(plane).insert(local_bounds);
+        }
+        app.update();
+        // the frustum the renderer would compute
+        let (projection, transform) = app
+            .world
+            .query::<(&Camera, &GlobalTransform)>()
+            .get(&app.world, camera)
+            .map(|(camera, transform)| (camera.projection_matrix(), transform.compute_matrix()))
+            .unwrap();
+        let frustum = Frustum::from_view_projection(&(projection * transform.inverse()));
+        app.world.entity_mut(camera).insert(frustum);
+
+        let on_screen = screen_point(&mut app, camera, Vec3::new(6.0, 0.0, 0.0));
+        click_at(&mut app, on_screen);
+        let hits: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| (event.hit.entity, event.hit.inside))
+            .collect();
+        // the plane out of view isn't tested, so doesn't even report its outside hit
+        assert_eq!(hits, [(straddling, true)]);
+    }
+
+    #[test]
+    fn priority_beats_distance() {
+        let mut app = app_with(IntersectPlanePlugin::default().mode(PickMode::Closest));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        let ui = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.world.entity_mut(ui).insert(PickPriority(1));
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        click_at(&mut app, center);
+
+        let hits = events::<PlaneHitEvent>(&app);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].hit.entity, ui);
+        assert_eq!(hits[0].hit.priority, 1);
+        assert_eq!(hovered(&app), Some(ui));
+    }
+
+    #[test]
+    fn focus_ignores_other_planes() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        let focused = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.world.resource_mut::<FocusPlane>().0 = Some(focused);
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        click_at(&mut app, center);
+
+        let hits: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| event.hit.entity)
+            .collect();
+        assert_eq!(hits, [focused]);
+        assert_eq!(hovered(&app), Some(focused));
+    }
+
+    #[test]
+    fn meshless_child_plane_picks_where_it_is() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        // no mesh or material, placed by its parent
+        let plane = app
+            .world
+            .spawn((MyPlane::new(2.0), TransformBundle::default()))
+            .id();
+        app.world
+            .spawn(TransformBundle::from_transform(Transform::from_xyz(
+                3.0, 1.0, 0.0,
+            )))
+            .add_child(plane);
+        app.update();
+        let point = Vec3::new(3.5, 1.0, 0.0);
+        let position = screen_point(&mut app, camera, point);
+        click_at(&mut app, position);
+
+        let hits = events::<PlaneHitEvent>(&app);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].hit.entity, plane);
+        assert!(hits[0].hit.inside);
+        assert!(hits[0].hit.world.abs_diff_eq(point, 1e-3));
+    }
+
+    #[test]
+    fn budgeted_hover_finds_the_full_pass_closest() {
+        let plugin = IntersectPlanePlugin::default().budget_per_frame(Some(3));
+        let mut app = app_with(plugin);
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 20.0, 0.1), Vec3::ZERO);
+        app.world.resource_mut::<PickingConfig>().tie_break = TieBreak::RenderOrder;
+        // the closest planes are spawned last, so only the last chunk tests them
+        for y in 0..9 {
+            spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, y as f32, 0.0));
+        }
+        // and they're level, so the one drawn on top wins though it comes later
+        let top = Transform::from_xyz(0.0, 9.0, 0.0);
+        spawn_plane(&mut app, 2.0, top);
+        let drawn_on_top = spawn_plane(&mut app, 2.0, top);
+        let material = app
+            .world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                depth_bias: 1.0,
+                ..default()
+            });
+        app.world.entity_mut(drawn_on_top).insert(material);
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        move_cursor(&mut app, Some(center));
+        let closest = run(&mut app, |picker: PlanePicker| {
+            picker.pick_closest().map(|hit| hit.entity)
+        });
+        assert_eq!(closest, Some(drawn_on_top));
+
+        // 3, 3, 3, then the last plane
+        for _ in 0..3 {
+            app.update();
+            assert_eq!(hovered(&app), None);
+        }
+        app.update();
+        assert_eq!(hovered(&app), closest);
+    }
+
+    #[test]
+    fn clicking_empty_space_records_the_miss() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let empty = screen_point(&mut app, camera, Vec3::new(5.0, 0.0, 0.0));
+        click_at(&mut app, empty);
+
+        let ray = app.world.resource::<LastMiss>().0.unwrap();
+        let misses = events::<PlaneMissEvent>(&app);
+        assert_eq!(misses.len(), 1);
+        assert_eq!(misses[0].camera, camera);
+        assert_eq!(misses[0].ray.origin, ray.origin);
+        // it points where the click was
+        let (distance, _) = ray.closest_approach(Vec3::new(5.0, 0.0, 0.0));
+        assert!(distance < 1e-3);
+
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        click_at(&mut app, center);
+        assert!(app.world.resource::<LastMiss>().0.is_none());
+    }
+
+    #[test]
+    fn moving_plane_updates_a_still_cursors_hover() {
+        let mut app = app_with(IntersectPlanePlugin::default().skip_idle_hover(true));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let platform = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        move_cursor(&mut app, Some(center));
+        app.world.send_event(CursorMoved {
+            window: Entity::PLACEHOLDER,
+            position: center,
+        });
+        app.update();
+        assert_eq!(hovered(&app), Some(platform));
+
+        // picking goes by the GlobalTransform, propagated at the end of the frame, so the hover
+        // follows a frame later
+        app.world
+            .get_mut::<Transform>(platform)
+            .unwrap()
+            .translation
+            .x = 5.0;
+        app.update();
+        app.update();
+        assert_eq!(hovered(&app), None);
+        app.world
+            .get_mut::<Transform>(platform)
+            .unwrap()
+            .translation
+            .x = 0.0;
+        app.update();
+        app.update();
+        assert_eq!(hovered(&app), Some(platform));
+
+        // and without anything moving, the hover is left alone
+        app.world.resource_mut::<HoveredPlane>().0 = None;
+        app.update();
+        assert_eq!(hovered(&app), None);
+    }
+
+    #[test]
+    fn clicks_outside_the_pick_region_are_ignored() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 100.0, Transform::IDENTITY);
+        app.update();
+        let center = window_center(&mut app);
+        // the left half of the viewport
+        let region = Rect::from_corners(Vec2::ZERO, Vec2::new(center.x, center.y * 2.0));
+        app.world.resource_mut::<PickingConfig>().pick_region = Some(region);
+
+        click_at(&mut app, center + Vec2::new(100.0, 0.0));
+        assert!(events::<PlaneHitEvent>(&app).is_empty());
+        assert_eq!(hovered(&app), None);
+        click_at(&mut app, center - Vec2::new(100.0, 0.0));
+        assert_eq!(events::<PlaneHitEvent>(&app).len(), 1);
+    }
+
+    #[test]
+    fn uv_origin_and_flip_v_orient_a_known_point() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 4.0, Transform::IDENTITY);
+        app.update();
+        // right of center and towards -Z, the top edge
+        let point = Vec3::new(1.0, 0.0, -1.5);
+        for (corner, uv) in [
+            (Corner::TopLeft, Vec2::new(0.75, 0.125)),
+            (Corner::TopRight, Vec2::new(0.25, 0.125)),
+            (Corner::BottomLeft, Vec2::new(0.75, 0.875)),
+            (Corner::BottomRight, Vec2::new(0.25, 0.875)),
+        ] {
+            for flip_v in [false, true] {
+                let mut config = app.world.resource_mut::<PickingConfig>();
+                config.uv_origin = corner;
+                config.flip_v = flip_v;
+                let hits = run(&mut app, move |picker: PlanePicker| {
+                    picker.pick_from_world_ray(point + Vec3::Y, Vec3::NEG_Y)
+                });
+                let expected = if flip_v {
+                    Vec2::new(uv.x, 1.0 - uv.y)
+                } else {
+                    uv
+                };
+                assert_eq!(hits[0].uv, expected, "{corner:?}, flip_v {flip_v}");
+                assert_eq!(hits[0].world, point);
+            }
+        }
+    }
+
+    #[test]
+    fn max_hits_keeps_the_nearest() {
+        let mut app = app_with(IntersectPlanePlugin::default().max_hits(Some(3)));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        // spawned back to front, so query order isn't the answer
+        let planes: Vec<_> = (0..5)
+            .map(|y| spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, y as f32, 0.0)))
+            .collect();
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        click_at(&mut app, center);
+
+        let hits: Vec<_> = events::<PlaneHitEvent>(&app)
+            .into_iter()
+            .map(|event| event.hit.entity)
+            .collect();
+        assert_eq!(hits, [planes[4], planes[3], planes[2]]);
+    }
+
+    #[test]
+    fn obb_rejection_agrees_with_the_exact_test() {
+        let planes = [
+            (MyPlane::new(2.0), Transform::from_xyz(0.5, -1.0, 0.2)),
+            (
+                MyPlane::new(3.0),
+                Transform::from_rotation(Quat::from_euler(EulerRot::XYZ, 0.4, 1.1, -0.3)),
+            ),
+            (
+                MyPlane::from_mesh_normal(2.0, Vec3::Z),
+                Transform::from_xyz(0.0, 1.0, -2.0)
+                    .with_rotation(Quat::from_rotation_y(0.7))
+                    .with_scale(Vec3::new(1.5, 0.5, 1.0)),
+            ),
+        ];
+        let origin = Vec3::new(0.3, 6.0, 4.0);
+        for (plane, transform) in planes {
+            let mut inside = 0;
+            // rays through a grid of points across and around the plane
+            for i in -8..=8 {
+                for j in -8..=8 {
+                    let uv = Vec2::new(i as f32, j as f32) / 10.0 + 0.5 + 0.013;
+                    let target = plane.point_at_uv(&transform, uv);
+                    let ray = Ray {
+                        origin,
+                        direction: (target - origin).normalize(),
+                    };
+                    let exact = PlaneHit::compute(
+                        ray,
+                        Entity::PLACEHOLDER,
+                        Entity::PLACEHOLDER,
+                        &transform,
+                        &plane,
+                    )
+                    .is_some_and(|hit| hit.inside);
+                    assert_eq!(ray_hits_obb(ray, &transform, &plane, 0.0), exact, "{uv}");
+                    inside += usize::from(exact);
+                }
+            }
+            // both kinds of ray were tried
+            assert!(inside > 0 && inside < 17 * 17);
+        }
+    }
+
+    #[test]
+    fn coincident_planes_have_a_consistent_winner() {
+        let mut app = app_with(IntersectPlanePlugin::default().mode(PickMode::Closest));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let first = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        // reuses the first's index, but is queried after the second
+        app.world.despawn(first);
+        let lowest = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        assert_eq!(lowest.index(), first.index());
+        app.update();
+        let center = window_center(&mut app);
+
+        for _ in 0..3 {
+            click_at(&mut app, center);
+            let winners: Vec<_> = events::<PlaneHitEvent>(&app)
+                .into_iter()
+                .map(|event| event.hit.entity)
+                .collect();
+            assert_eq!(winners.last(), Some(&lowest));
+            assert_eq!(hovered(&app), Some(lowest));
+            app.update();
+        }
+    }
+
+    /// A projection Bevy knows nothing about: a perspective shifted off-center
+    #[derive(Component)]
+    struct OffCenter {
+        aspect: f32,
+    }
+
+    impl CameraProjection for OffCenter {
+        fn get_projection_matrix(&self) -> Mat4 {
+            Mat4::from_translation(Vec3::new(0.3, -0.2, 0.0))
+                * Mat4::perspective_infinite_reverse_rh(1.0, self.aspect, 0.1)
+        }
+
+        fn update(&mut self, width: f32, height: f32) {
+            self.aspect = width / height;
+        }
+
+        fn far(&self) -> f32 {
+            f32::INFINITY
+        }
+    }
+
+    #[test]
+    fn custom_projections_pick_where_they_draw() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        app.add_systems(PreUpdate, camera_system::<OffCenter>);
+        let transform = Transform::from_xyz(0.0, 10.0, 0.1).looking_at(Vec3::ZERO, Vec3::Y);
+        let camera = app
+            .world
+            .spawn((
+                Camera::default(),
+                OffCenter { aspect: 1.0 },
+                transform,
+                GlobalTransform::from(transform),
+                MainCamera,
+            ))
+            .id();
+        spawn_plane(&mut app, 4.0, Transform::IDENTITY);
+        app.update();
+
+        let target = Vec3::new(0.5, 0.0, -1.0);
+        let position = screen_point(&mut app, camera, target);
+        // shifted, so the point isn't where a centered perspective would draw it
+        assert!(position.distance(window_center(&mut app)) > 100.0);
+        click_at(&mut app, position);
+        let hits = events::<PlaneHitEvent>(&app);
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].hit.world.abs_diff_eq(target, 1e-3));
+        assert!(hits[0].hit.inside);
+    }
+
+    #[test]
+    fn back_clicks_mirror_u() {
+        // the uv and face of a click on the same point from above or below
+        let click_from = |height: f32| {
+            let mut app = app_with(IntersectPlanePlugin::default().mirror_back_uv(true));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, height, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 4.0, Transform::IDENTITY);
+            app.update();
+            let position = screen_point(&mut app, camera, Vec3::new(1.0, 0.0, 0.5));
+            click_at(&mut app, position);
+            let hit = events::<PlaneHitEvent>(&app)[0].hit;
+            (hit.uv, hit.face)
+        };
+        let (front, front_face) = click_from(10.0);
+        let (back, back_face) = click_from(-10.0);
+        assert_eq!((front_face, back_face), (Face::Front, Face::Back));
+        assert!(front.abs_diff_eq(Vec2::new(0.75, 0.625), 1e-3));
+        assert!(back.abs_diff_eq(Vec2::new(1.0 - front.x, front.y), 1e-3));
+    }
+
+    #[test]
+    fn zero_size_viewports_pick_nothing() {
+        let mut app = app_with(IntersectPlanePlugin::default());
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        app.world.get_mut::<Camera>(camera).unwrap().viewport = Some(Viewport {
+            physical_position: UVec2::ZERO,
+            physical_size: UVec2::ZERO,
+            ..default()
+        });
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let center = window_center(&mut app);
+        click_at(&mut app, center);
+        app.update();
+
+        assert!(run(&mut app, |picker: PlanePicker| picker.cursor_ray()).is_none());
+        assert!(events::<PlaneHitEvent>(&app).is_empty());
+        assert_eq!(hovered(&app), None);
+    }
+
+    #[test]
+    fn facing_weight_prefers_planes_facing_the_ray() {
+        let ray = Ray {
+            origin: Vec3::Y * 10.0,
+            direction: Vec3::NEG_Y,
+        };
+        let hit = |entity, transform: Transform| {
+            PlaneHit::compute(
+                ray,
+                Entity::PLACEHOLDER,
+                entity,
+                &transform,
+                &MyPlane::new(4.0),
+            )
+            .unwrap()
+        };
+        let facing = hit(Entity::from_raw(1), Transform::from_xyz(0.0, 4.8, 0.0));
+        // a little nearer, but nearly edge-on
+        let tilt = Quat::from_rotation_z(75f32.to_radians());
+        let steep = hit(
+            Entity::from_raw(2),
+            Transform::from_xyz(0.0, 5.0, 0.0).with_rotation(tilt),
+        );
+        assert!(steep.distance < facing.distance && facing.distance - steep.distance < 0.5);
+
+        let closest = |facing_weight| {
+            let settings = PickSettings {
+                facing_weight,
+                ..default()
+            };
+            let mut hits = vec![steep, facing];
+            apply_mode(PickMode::Closest, &settings, ray, &mut hits);
+            hits[0].entity
+        };
+        assert_eq!(closest(0.0), steep.entity);
+        assert_eq!(closest(1.0), facing.entity);
+    }
+
+    #[test]
+    fn a_horizontal_desired_normal_selects_the_wall() {
+        let mut app = app_with(IntersectPlanePlugin::default().mode(PickMode::Closest));
+        let camera = spawn_camera(&mut app, Vec3::new(5.0, 5.0, 0.0), Vec3::ZERO);
+        let floor = spawn_plane(&mut app, 10.0, Transform::IDENTITY);
+        // facing +X, behind the floor along rays through the middle of the view
+        let facing_x = Quat::from_rotation_z(-FRAC_PI_2);
+        let wall = spawn_plane(
+            &mut app,
+            10.0,
+            Transform::from_xyz(-2.0, 0.0, 0.0).with_rotation(facing_x),
+        );
+        app.update();
+        let center = screen_point(&mut app, camera, Vec3::ZERO);
+        let closest = |app: &mut App| {
+            click_at(app, center);
+            let hit = events::<PlaneHitEvent>(app).last().unwrap().hit;
+            app.update();
+            hit
+        };
+        assert_eq!(closest(&mut app).entity, floor);
+
+        app.world.resource_mut::<PickingConfig>().desired_normal =
+            Some(DesiredNormal::new(Vec3::X, 0.2));
+        let hit = closest(&mut app);
+        assert_eq!(hit.entity, wall);
+        assert!(hit.normal.abs_diff_eq(Vec3::X, 1e-5));
+    }
+
+    #[test]
+    fn smoothed_hover_uv_converges_after_a_jump() {
+        let mut app = app_with(IntersectPlanePlugin::default().hover_smoothing(0.5));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let start = screen_point(&mut app, camera, Vec3::new(-0.5, 0.0, 0.0));
+        move_cursor(&mut app, Some(start));
+        app.update();
+        let end = screen_point(&mut app, camera, Vec3::new(0.5, 0.0, 0.0));
+        move_cursor(&mut app, Some(end));
+
+        let mut gap = f32::INFINITY;
+        for _ in 0..12 {
+            app.update();
+            let hover = app.world.resource::<HoverUv>();
+            let (uv, raw) = (hover.uv.unwrap(), hover.raw_uv.unwrap());
+            assert!(
+                (raw.x - 0.75).abs() < 1e-3,
+                "raw uv jumps straight away: {raw}"
+            );
+            let next = uv.distance(raw);
+            assert!(next < gap, "{next} should shrink from {gap}");
+            gap = next;
+        }
+        assert!(gap < 1e-3, "smoothed uv is still {gap} away");
+    }
+
+    #[test]
+    fn pixel_tolerances_grow_with_distance_and_world_ones_dont() {
+        // a tenth of a unit past the edge is a dozen or so pixels from 5 units away, and only a
+        // few from 20
+        let near_edge = Vec3::new(1.1, 0.0, 0.0);
+        for (mode, tolerance, inside_near, inside_far) in [
+            (ToleranceMode::WorldUnits, 0.15, true, true),
+            (ToleranceMode::WorldUnits, 0.05, false, false),
+            (ToleranceMode::Pixels, 8.0, false, true),
+        ] {
+            for (height, expected) in [(5.0, inside_near), (20.0, inside_far)] {
+                let plugin = IntersectPlanePlugin::default()
+                    .pick_tolerance(tolerance)
+                    .tolerance_mode(mode);
+                let mut app = app_with(plugin);
+                let camera = spawn_camera(&mut app, Vec3::new(0.0, height, 0.1), Vec3::ZERO);
+                spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+                app.update();
+                let position = screen_point(&mut app, camera, near_edge);
+                click_at(&mut app, position);
+
+                let hit = events::<PlaneHitEvent>(&app).last().unwrap().hit;
+                assert_eq!(hit.inside, expected, "{mode:?} {tolerance} from {height}");
+            }
+        }
+    }
+
+    #[test]
+    fn planes_beyond_the_far_clip_plane_are_missed_when_respected() {
+        for respect in [false, true] {
+            let plugin = IntersectPlanePlugin::default().respect_clip_planes(respect);
+            let mut app = app_with(plugin);
+            // the default perspective projection's far plane is 1000 units out
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 1200.0, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 100.0, Transform::IDENTITY);
+            app.update();
+            let center = screen_point(&mut app, camera, Vec3::ZERO);
+            click_at(&mut app, center);
+
+            assert_eq!(events::<PlaneHitEvent>(&app).is_empty(), respect);
+        }
+    }
+
+    #[test]
+    fn near_misses_within_the_cursor_radius_snap_onto_the_edge() {
+        // 0.05 units past the edge is about 9 pixels from 5 units away
+        let near_miss = Vec3::new(1.05, 0.0, 0.0);
+        for (radius, snapped) in [(0.0, false), (4.0, false), (15.0, true)] {
+            let mut app = app_with(IntersectPlanePlugin::default().cursor_radius(radius));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 5.0, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+            app.update();
+            let position = screen_point(&mut app, camera, near_miss);
+            click_at(&mut app, position);
+
+            let hit = events::<PlaneHitEvent>(&app).last().unwrap().hit;
+            assert_eq!(hit.inside, snapped, "radius {radius}");
+            let expected = if snapped { Vec3::X } else { near_miss };
+            assert!(
+                hit.world.abs_diff_eq(expected, 1e-3),
+                "radius {radius}: {}",
+                hit.world
+            );
+        }
+    }
+
+    #[test]
+    fn a_locked_cursor_picks_through_the_middle() {
+        for center in [false, true] {
+            let mut app = app_with(IntersectPlanePlugin::default().locked_cursor_center(center));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            let middle = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+            let side = spawn_plane(&mut app, 2.0, Transform::from_xyz(3.0, 0.0, 0.0));
+            app.update();
+            // the window stops reporting the cursor while it's locked
+            let window = primary_window(&mut app);
+            let mut window = app.world.get_mut::<Window>(window).unwrap();
+            window.cursor.grab_mode = CursorGrabMode::Locked;
+            window.set_cursor_position(None);
+            app.update();
+            assert_eq!(hovered(&app), center.then_some(middle));
+
+            // a stale position is ignored while picking through the middle
+            let over_side = screen_point(&mut app, camera, Vec3::new(3.0, 0.0, 0.0));
+            move_cursor(&mut app, Some(over_side));
+            app.update();
+            assert_eq!(hovered(&app), Some(if center { middle } else { side }));
+        }
+    }
+
+    #[test]
+    fn shrink_margins_move_the_edges_both_ways() {
+        let just_inside = Vec3::new(0.9, 0.0, 0.0);
+        let just_outside = Vec3::new(1.1, 0.0, 0.0);
+        for (margin, point, inside) in [
+            (0.0, just_inside, true),
+            (0.2, just_inside, false),
+            (0.0, just_outside, false),
+            (-0.2, just_outside, true),
+        ] {
+            let mut app = app_with(IntersectPlanePlugin::default().shrink_margin(margin));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+            app.update();
+            let position = screen_point(&mut app, camera, point);
+            click_at(&mut app, position);
+
+            let hit = events::<PlaneHitEvent>(&app).last().unwrap().hit;
+            assert_eq!(hit.inside, inside, "margin {margin} at {point}");
+        }
+    }
+
+    #[test]
+    fn heightfields_lift_hits_along_the_normal() {
+        let mut app = headless_app();
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let tilted = Transform::from_rotation(Quat::from_rotation_z(0.4));
+        let plane = spawn_plane(&mut app, 4.0, tilted);
+        app.update();
+        let position = screen_point(&mut app, camera, Vec3::new(0.5, 0.0, 0.5));
+        let pick = |app: &mut App| {
+            click_at(app, position);
+            let hit = events::<PlaneHitEvent>(app).last().unwrap().hit;
+            app.update();
+            hit
+        };
+        let flat = pick(&mut app);
+
+        app.world
+            .entity_mut(plane)
+            .insert(Heightfield::new(|_| 0.5));
+        let lifted = pick(&mut app);
+        let normal = tilted.rotation * Vec3::Y;
+        assert!((lifted.world - flat.world).abs_diff_eq(normal * 0.5, 1e-4));
+        assert_eq!(lifted.distance, flat.distance);
+    }
+
+    #[test]
+    fn unit_scale_scales_reported_measurements() {
+        let mut app = app_with(IntersectPlanePlugin::default().unit_scale(100.0));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.0001), Vec3::ZERO);
+        spawn_plane(&mut app, 4.0, Transform::IDENTITY);
+        app.update();
+        let position = screen_point(&mut app, camera, Vec3::new(1.0, 0.0, -0.5));
+        click_at(&mut app, position);
+
+        let hit = events::<PlaneHitEvent>(&app).last().unwrap().hit;
+        assert!(hit.world.abs_diff_eq(Vec3::new(1.0, 0.0, -0.5), 1e-3));
+        assert!(hit
+            .scaled_world()
+            .abs_diff_eq(Vec3::new(100.0, 0.0, -50.0), 0.1));
+        assert!((hit.scaled_distance() - hit.distance * 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn no_plane_in_a_ray_behind_or_alongside_them() {
+        let plane = MyPlane::new(2.0);
+        let transforms = [
+            Transform::IDENTITY,
+            Transform::from_xyz(0.0, 0.0, 5.0).with_rotation(Quat::from_rotation_x(FRAC_PI_2)),
+        ];
+        let planes = || transforms.iter().map(|transform| (transform, &plane));
+        let ray = |origin: Vec3, direction: Vec3| Ray { origin, direction };
+
+        // heading away from both
+        assert!(!any_plane_in_ray(
+            ray(Vec3::new(0.0, 1.0, 0.0), Vec3::Y),
+            planes()
+        ));
+        // parallel to the floor, above it, and heading away from the wall
+        assert!(!any_plane_in_ray(
+            ray(Vec3::new(0.0, 1.0, 0.0), Vec3::NEG_Z),
+            planes()
+        ));
+        assert!(any_plane_in_ray(
+            ray(Vec3::new(0.0, 1.0, 0.0), Vec3::NEG_Y),
+            planes()
+        ));
+        assert!(any_plane_in_ray(
+            ray(Vec3::new(0.0, 0.5, 0.0), Vec3::Z),
+            planes()
+        ));
+    }
+
+    #[test]
+    fn culling_backfaces_spares_double_sided_materials() {
+        let mut app = app_with(IntersectPlanePlugin::default().cull_backfaces(true));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let mut materials = app.world.resource_mut::<Assets<StandardMaterial>>();
+        let single = materials.add(StandardMaterial::default());
+        let double = materials.add(StandardMaterial {
+            cull_mode: None,
+            ..default()
+        });
+        let single_sided = spawn_plane(&mut app, 2.0, Transform::from_xyz(-1.5, 0.0, 0.0));
+        let double_sided = spawn_plane(&mut app, 2.0, Transform::from_xyz(1.5, 0.0, 0.0));
+        app.world.entity_mut(single_sided).insert(single);
+        app.world.entity_mut(double_sided).insert(double);
+        app.update();
+
+        let hit_from = |app: &mut App, x: f32, y: f32| {
+            run(app, move |picker: PlanePicker| {
+                picker
+                    .pick_from_world_ray(Vec3::new(x, y, 0.0), Vec3::new(0.0, -y, 0.0))
+                    .into_iter()
+                    .filter(|hit| hit.inside)
+                    .map(|hit| (hit.entity, hit.face))
+                    .collect::<Vec<_>>()
+            })
+        };
+        assert_eq!(hit_from(&mut app, -1.5, 5.0), [(single_sided, Face::Front)]);
+        assert_eq!(hit_from(&mut app, -1.5, -5.0), []);
+        assert_eq!(hit_from(&mut app, 1.5, 5.0), [(double_sided, Face::Front)]);
+        assert_eq!(hit_from(&mut app, 1.5, -5.0), [(double_sided, Face::Back)]);
+    }
+
+    #[test]
+    fn hover_substeps_catch_a_thin_plane_flicked_across() {
+        for (substeps, caught) in [(1, false), (8, true)] {
+            let mut app = app_with(IntersectPlanePlugin::default().hover_substeps(substeps));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            let thin = spawn_plane(&mut app, 0.2, Transform::IDENTITY);
+            app.update();
+            let from = screen_point(&mut app, camera, Vec3::new(-3.0, 0.0, 0.0));
+            let to = screen_point(&mut app, camera, Vec3::new(3.0, 0.0, 0.0));
+            move_cursor(&mut app, Some(from));
+            app.update();
+            assert_eq!(hovered(&app), None);
+
+            move_cursor(&mut app, Some(to));
+            app.update();
+            assert_eq!(hovered(&app), caught.then_some(thin), "{substeps} substeps");
+        }
+    }
+
+    #[test]
+    fn sampled_colors_of_solid_planes_are_their_base_color() {
+        let mut app = app_with(IntersectPlanePlugin::default().sample_color(true));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let plane = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        let material = app
+            .world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(Color::rgb(0.2, 0.6, 0.9).into());
+        app.world.entity_mut(plane).insert(material);
+        app.update();
+        let position = screen_point(&mut app, camera, Vec3::new(0.3, 0.0, -0.4));
+        click_at(&mut app, position);
+
+        let hit = events::<PlaneHitEvent>(&app).last().unwrap().hit;
+        assert_eq!(hit.sampled_color, Some(Color::rgb(0.2, 0.6, 0.9)));
+    }
+
+    #[test]
+    fn dragged_planes_leave_the_hover_to_whats_beneath() {
+        let mut app = app_with(IntersectPlanePlugin::default().hover_ignores_dragged(true));
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let floor = spawn_plane(&mut app, 10.0, Transform::IDENTITY);
+        let card = spawn_plane(&mut app, 1.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        app.update();
+        let position = screen_point(&mut app, camera, Vec3::new(0.0, 1.0, 0.0));
+        move_cursor(&mut app, Some(position));
+        app.update();
+        assert_eq!(hovered(&app), Some(card));
+
+        send_button(&mut app, MouseButton::Left, ButtonState::Pressed);
+        app.update();
+        assert_eq!(hovered(&app), Some(floor));
+
+        send_button(&mut app, MouseButton::Left, ButtonState::Released);
+        app.update();
+        assert_eq!(hovered(&app), Some(card));
+    }
+
+    #[test]
+    fn planes_more_transparent_than_min_alpha_are_skipped() {
+        let mut app = app_with(IntersectPlanePlugin::default().min_alpha(Some(0.5)));
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let floor = spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        let glass = spawn_plane(&mut app, 2.0, Transform::from_xyz(0.0, 1.0, 0.0));
+        let mut materials = app.world.resource_mut::<Assets<StandardMaterial>>();
+        let opaque = materials.add(Color::WHITE.into());
+        let transparent = materials.add(Color::rgba(1.0, 1.0, 1.0, 0.2).into());
+        app.world.entity_mut(floor).insert(opaque);
+        app.world.entity_mut(glass).insert(transparent);
+        app.update();
+
+        let hits = run(&mut app, |picker: PlanePicker| {
+            picker
+                .pick_from_world_ray(Vec3::Y * 5.0, Vec3::NEG_Y)
+                .into_iter()
+                .map(|hit| hit.entity)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(hits, [floor]);
+    }
+
+    #[test]
+    fn selecting_by_distance_to_a_point_picks_the_nearest_floor() {
+        let player = Vec3::new(0.0, 3.5, 0.0);
+        for (select_by, expected) in [
+            (SelectBy::RayDistance, 2),
+            (SelectBy::DistanceToPoint(player), 1),
+        ] {
+            let mut app = app_with(IntersectPlanePlugin::default().select_by(select_by));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            let floors = [0.0, 3.0, 6.0]
+                .map(|y| spawn_plane(&mut app, 4.0, Transform::from_xyz(0.0, y, 0.0)));
+            app.update();
+            let position = screen_point(&mut app, camera, Vec3::ZERO);
+            move_cursor(&mut app, Some(position));
+            app.update();
+            assert_eq!(hovered(&app), Some(floors[expected]), "{select_by:?}");
+        }
+    }
+
+    #[test]
+    fn camera_origin_rays_measure_from_the_camera() {
+        let distance = |origin: RayOrigin| {
+            let mut app = app_with(IntersectPlanePlugin::default().ray_origin(origin));
+            spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+            app.update();
+            let center = window_center(&mut app);
+            click_at(&mut app, center);
+            events::<PlaneHitEvent>(&app).last().unwrap().hit.distance
+        };
+        let near = PerspectiveProjection::default().near;
+
+        let from_camera = distance(RayOrigin::CameraOrigin);
+        assert!((from_camera - Vec3::new(0.0, 10.0, 0.1).length()).abs() < 1e-3);
+        // straight down the view, the near plane is `near` in front of the camera
+        assert!((from_camera - distance(RayOrigin::NearPlane) - near).abs() < 1e-3);
+    }
+
+    #[test]
+    fn hits_report_the_press_sub_frame_once() {
+        let mut app = headless_app();
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let center = window_center(&mut app);
+
+        app.world.resource_mut::<PressSubFrame>().0 = Some(0.25);
+        click_at(&mut app, center);
+        assert_eq!(
+            events::<PlaneHitEvent>(&app).last().unwrap().hit.sub_frame,
+            0.25
+        );
+        assert_eq!(app.world.resource::<PressSubFrame>().0, None);
+
+        app.update();
+        click_at(&mut app, center);
+        assert_eq!(
+            events::<PlaneHitEvent>(&app).last().unwrap().hit.sub_frame,
+            1.0
+        );
+    }
+
+    #[test]
+    fn planes_smaller_on_screen_than_the_minimum_are_skipped() {
+        for min_size in [None, Some(20.0)] {
+            let mut app = app_with(IntersectPlanePlugin::default().min_screen_size_px(min_size));
+            spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            // hundreds of pixels across, and a pixel or two
+            let near = spawn_plane(&mut app, 4.0, Transform::from_xyz(0.0, 5.0, 0.0));
+            let far = spawn_plane(&mut app, 0.1, Transform::from_xyz(0.0, -50.0, 0.0));
+            app.update();
+
+            let hits = run(&mut app, |picker: PlanePicker| {
+                picker
+                    .pick_from_world_ray(Vec3::Y * 10.0, Vec3::NEG_Y)
+                    .into_iter()
+                    .map(|hit| hit.entity)
+                    .collect::<Vec<_>>()
+            });
+            let expected = match min_size {
+                None => vec![near, far],
+                Some(_) => vec![near],
+            };
+            assert_eq!(hits, expected);
+        }
+    }
+
+    #[test]
+    fn shift_clicks_report_shift_held() {
+        let mut app = headless_app();
+        spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 2.0, Transform::IDENTITY);
+        app.update();
+        let center = window_center(&mut app);
+        let modifiers = |app: &App| events::<PlaneHitEvent>(app).last().unwrap().hit.modifiers;
+
+        click_at(&mut app, center);
+        assert_eq!(modifiers(&app), Modifiers::default());
+
+        app.update();
+        send_key(&mut app, KeyCode::ShiftRight, ButtonState::Pressed);
+        click_at(&mut app, center);
+        let shift = Modifiers {
+            shift: true,
+            ..default()
+        };
+        assert_eq!(modifiers(&app), shift);
+    }
+
+    #[test]
+    fn seam_clicks_merge_into_one_hit() {
+        for epsilon in [None, Some(0.01)] {
+            let mut app = app_with(IntersectPlanePlugin::default().seam_epsilon(epsilon));
+            let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            let left = spawn_plane(&mut app, 2.0, Transform::from_xyz(-1.0, 0.0, 0.0));
+            let right = spawn_plane(&mut app, 2.0, Transform::from_xyz(1.0, 0.0, 0.0));
+            app.update();
+            let seam = screen_point(&mut app, camera, Vec3::new(0.0, 0.0, 0.5));
+            click_at(&mut app, seam);
+
+            let mut hits: Vec<_> = events::<PlaneHitEvent>(&app)
+                .into_iter()
+                .map(|event| (event.hit.entity, event.hit.inside))
+                .collect();
+            hits.sort();
+            match epsilon {
+                None => assert_eq!(hits, [(left, true), (right, true)]),
+                // the lower entity index wins a tie
+                Some(_) => assert_eq!(hits, [(left, true)]),
+            }
+        }
+    }
+
+    #[test]
+    fn ray_origin_overrides_shift_hits_along_parallel_rays() {
+        let eye = Vec3::new(0.0, 10.0, 0.1);
+        // picking from an entity at `pivot`, if there is one, instead of `origin`
+        let hit_from = |origin: RayOrigin, pivot: Option<Vec3>| {
+            let mut app = app_with(IntersectPlanePlugin::default().ray_origin(origin));
+            let camera = spawn_camera(&mut app, eye, Vec3::ZERO);
+            spawn_plane(&mut app, 10.0, Transform::IDENTITY);
+            if let Some(pivot) = pivot {
+                let entity = app
+                    .world
+                    .spawn(GlobalTransform::from_translation(pivot))
+                    .id();
+                app.world.resource_mut::<PickingConfig>().ray_origin = RayOrigin::Entity(entity);
+            }
+            app.update();
+            let position = screen_point(&mut app, camera, Vec3::X);
+            click_at(&mut app, position);
+            events::<PlaneHitEvent>(&app).last().unwrap().hit
+        };
+        let from_camera = hit_from(RayOrigin::CameraOrigin, None);
+        assert!(from_camera.world.abs_diff_eq(Vec3::X, 1e-3));
+
+        let offset = Vec3::new(1.0, 0.0, -2.0);
+        let from_point = hit_from(RayOrigin::Point(eye + offset), None);
+        let from_entity = hit_from(RayOrigin::CameraOrigin, Some(eye - offset));
+        assert!(
+            from_point.world.abs_diff_eq(Vec3::X + offset, 1e-3),
+            "{}",
+            from_point.world
+        );
+        assert!(
+            from_entity.world.abs_diff_eq(Vec3::X - offset, 1e-3),
+            "{}",
+            from_entity.world
+        );
+        for hit in [from_point, from_entity] {
+            assert!((hit.distance - from_camera.distance).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn only_touches_that_barely_move_are_taps() {
+        for (threshold, moved, tapped) in
+            [(10.0, 5.0, true), (10.0, 30.0, false), (40.0, 30.0, true)]
+        {
+            let mut app = app_with(IntersectPlanePlugin::default().tap_threshold_px(threshold));
+            spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+            let plane = spawn_plane(&mut app, 4.0, Transform::IDENTITY);
+            app.update();
+            let center = window_center(&mut app);
+            send_touch(&mut app, 0, TouchPhase::Started, center);
+            app.update();
+            let lifted = center + Vec2::new(moved, 0.0);
+            send_touch(&mut app, 0, TouchPhase::Moved, lifted);
+            send_touch(&mut app, 0, TouchPhase::Ended, lifted);
+            app.update();
+
+            let hits: Vec<_> = events::<PlaneHitEvent>(&app)
+                .into_iter()
+                .map(|event| event.hit.entity)
+                .collect();
+            assert_eq!(
+                hits,
+                if tapped { vec![plane] } else { vec![] },
+                "moved {moved} of {threshold}px"
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{picking::ClickInput, MainCamera, MyPlane, PlaneHit, PlanePicker};
+
+/// The hit pinned by a press of the pick button while
+/// [`PickSettings::pin_confirm_key`](crate::PickSettings::pin_confirm_key) is set, for two-step
+/// placement: press to pick a point, adjust it (e.g. its height) while the cursor
+/// wanders off, then confirm. For the same instance as [`HoveredPlane`](crate::HoveredPlane).
+///
+/// Moving the cursor or pressing again doesn't change a pinned hit. The confirm key sends a
+/// [`PinnedPickEvent`] with it and unpins it, so the next press pins a new one.
+#[derive(Resource)]
+pub struct PinnedPick<C = MainCamera, P = MyPlane> {
+    /// The pinned hit, `None` until a press lands inside a plane
+    pub hit: Option<PlaneHit>,
+    marker: PhantomData<fn() -> (C, P)>,
+}
+
+impl<C, P> Default for PinnedPick<C, P> {
+    fn default() -> Self {
+        Self {
+            hit: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Sent with the [`PinnedPick`] hit when it's confirmed, and only then: while it's pinned, read
+/// the hit from the [`PinnedPick`] resource.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PinnedPickEvent(pub PlaneHit);
+
+pub(crate) fn track_pin<C: Component, P: Component>(
+    picker: PlanePicker<C, P>,
+    input: ClickInput,
+    keys: Res<Input<KeyCode>>,
+    mut pinned: ResMut<PinnedPick<C, P>>,
+    mut events: EventWriter<PinnedPickEvent>,
+) {
+    let Some(confirm_key) = picker.config().pin_confirm_key else {
+        return;
+    };
+    match pinned.hit {
+        Some(hit) if keys.just_pressed(confirm_key) => {
+            events.send(PinnedPickEvent(hit));
+            pinned.hit = None;
+        }
+        Some(_) => {}
+        None => {
+            let ray = input
+                .click(picker.config())
+                .and_then(|pointer| picker.pointer_ray(pointer));
+            if let Some(hit) = ray.and_then(|ray| picker.pick_closest_ray(ray)) {
+                pinned.hit = Some(hit);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::ButtonState;
+
+    use super::*;
+    use crate::{
+        test_util::{
+            app_with, click_at, events, move_cursor, screen_point, send_key, spawn_camera,
+            spawn_plane,
+        },
+        IntersectPlanePlugin,
+    };
+
+    #[test]
+    fn moving_after_pinning_keeps_the_pinned_point() {
+        let plugin = IntersectPlanePlugin::default().pin_confirm_key(Some(KeyCode::Return));
+        let mut app = app_with(plugin);
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        spawn_plane(&mut app, 4.0, Transform::IDENTITY);
+        app.update();
+        let pin_at = screen_point(&mut app, camera, Vec3::new(1.0, 0.0, 0.0));
+        click_at(&mut app, pin_at);
+        let pinned = app.world.resource::<PinnedPick>().hit.unwrap().world;
+        assert!(pinned.abs_diff_eq(Vec3::X, 1e-3));
+
+        // wandering off, and even pressing again elsewhere, leaves it where it was
+        let elsewhere = screen_point(&mut app, camera, Vec3::new(-1.0, 0.0, 1.0));
+        move_cursor(&mut app, Some(elsewhere));
+        app.update();
+        click_at(&mut app, elsewhere);
+        app.update();
+        assert_eq!(
+            app.world.resource::<PinnedPick>().hit.unwrap().world,
+            pinned
+        );
+        assert!(events::<PinnedPickEvent>(&app).is_empty());
+
+        // confirming sends it as it was pinned
+        send_key(&mut app, KeyCode::Return, ButtonState::Pressed);
+        app.update();
+        let confirmed: Vec<_> = events::<PinnedPickEvent>(&app)
+            .into_iter()
+            .map(|PinnedPickEvent(hit)| hit.world)
+            .collect();
+        assert_eq!(confirmed, [pinned]);
+        assert!(app.world.resource::<PinnedPick>().hit.is_none());
+    }
+}
@@ -0,0 +1,563 @@
+use bevy::{prelude::*, render::primitives::Aabb};
+
+use crate::{is_ray_parallel, CustomBounds};
+
+#[derive(Component, Clone, Copy)]
+pub struct MyPlane {
+    pub size: f32,
+    /// Treat the side the mesh's back faces as the plane's front, without turning the transform,
+    /// e.g. for imported meshes whose normal points the other way: hits from that side report
+    /// [`Face::Front`](crate::Face::Front), and are the ones kept by
+    /// [`PickSettings::cull_backfaces`](crate::PickSettings::cull_backfaces). See
+    /// [`MyPlane::front_normal`].
+    pub invert_normal: bool,
+    /// The rotation from the mesh `shape::Plane` builds, normal +Y, to the plane's actual mesh,
+    /// for meshes built facing another way, e.g. `shape::Quad`, which faces +Z. Picking applies
+    /// it after the transform's rotation, and measures uvs, `local_mesh_point` and `local_normal`
+    /// in the turned frame, so they read the same as for a `shape::Plane`. See
+    /// [`MyPlane::from_mesh_normal`].
+    pub mesh_rotation: Quat,
+}
+
+impl MyPlane {
+    /// The normal of the unrotated plane: the front of the mesh `shape::Plane` builds faces +Y.
+    pub const LOCAL_NORMAL: Vec3 = Vec3::Y;
+    /// The horizontal axis of the unrotated plane, along which uv.x increases.
+    pub const LOCAL_TANGENT: Vec3 = Vec3::X;
+    /// The vertical axis of the unrotated plane, along which uv.y increases.
+    pub const LOCAL_BITANGENT: Vec3 = Vec3::Z;
+
+    pub fn new(size: f32) -> Self {
+        Self {
+            size,
+            invert_normal: false,
+            mesh_rotation: Quat::IDENTITY,
+        }
+    }
+
+    /// A plane for a square mesh of side `size` whose front face points along `normal_axis` in
+    /// its own space, rather than +Y like `shape::Plane`, e.g. `Vec3::Z` for `shape::Quad`.
+    ///
+    /// The plane's axes are turned by the shortest rotation taking +Y onto `normal_axis`, so in
+    /// the mesh's own space uv.x and uv.y run along:
+    ///
+    /// | `normal_axis` | uv.x | uv.y |
+    /// |---------------|------|------|
+    /// | `Vec3::X`     | -Y   | +Z   |
+    /// | `Vec3::Y`     | +X   | +Z   |
+    /// | `Vec3::Z`     | +X   | -Y   |
+    ///
+    /// `Vec3::Y` is the same as [`MyPlane::new`], and `Vec3::Z` matches the uvs `shape::Quad`
+    /// builds. The transform's rotation turns all of these as usual.
+    pub fn from_mesh_normal(size: f32, normal_axis: Vec3) -> Self {
+        Self {
+            mesh_rotation: Quat::from_rotation_arc(Self::LOCAL_NORMAL, normal_axis.normalize()),
+            ..Self::new(size)
+        }
+    }
+
+    /// `transform` turned by [`MyPlane::mesh_rotation`], so that the plane's standard frame
+    /// applies to it: [`MyPlane::axes`] and [`MyPlane::to_local`] given the result follow the
+    /// plane's actual mesh.
+    ///
+    /// The scale is turned along with it, so its X and Z are the stretch along the plane's uv
+    /// axes. That's exact for the mesh rotations [`MyPlane::from_mesh_normal`] builds from a
+    /// coordinate axis, and it's by magnitude: a negative scale doesn't mirror the plane.
+    pub fn oriented(&self, transform: &Transform) -> Transform {
+        Transform {
+            rotation: transform.rotation * self.mesh_rotation,
+            scale: (self.mesh_rotation.inverse() * transform.scale).abs(),
+            ..*transform
+        }
+    }
+    pub fn to_plane(self) -> shape::Plane {
+        shape::Plane::from_size(self.size)
+    }
+
+    /// The smallest plane through the points `a`, `b` and `c` that covers all three, and the
+    /// transform that places it, for surfaces defined by points rather than a transform and size.
+    /// Spawn both on an entity to pick it.
+    ///
+    /// The horizontal axis runs from `a` towards `b`, and the normal points out of the side from
+    /// which `a`, `b` and `c` run clockwise, like [`MyPlane::corners`]. Returns `None` if the
+    /// points are in a line, so there's no single plane through them.
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Option<(Self, Transform)> {
+        let x_axis = (b - a).normalize_or_zero();
+        let normal = (c - a).cross(b - a).normalize_or_zero();
+        if x_axis == Vec3::ZERO || normal == Vec3::ZERO {
+            return None;
+        }
+        let z_axis = x_axis.cross(normal);
+        let local =
+            [a, b, c].map(|point| Vec2::new((point - a).dot(x_axis), (point - a).dot(z_axis)));
+        let min = local.into_iter().fold(local[0], Vec2::min);
+        let max = local.into_iter().fold(local[0], Vec2::max);
+        let center = (min + max) * 0.5;
+        let transform = Transform {
+            translation: a + x_axis * center.x + z_axis * center.y,
+            rotation: Quat::from_mat3(&Mat3::from_cols(x_axis, normal, z_axis)),
+            ..default()
+        };
+        Some((Self::new((max - min).max_element()), transform))
+    }
+
+    /// Like [`MyPlane::from_points`], but only the triangle `a`, `b`, `c` counts as inside, with
+    /// [`CustomBounds`] testing whether a hit lands within it. Spawn all three components on an
+    /// entity to pick it.
+    ///
+    /// Drawing the triangle is up to you: [`MyPlane::to_plane`] would draw the whole square.
+    pub fn from_triangle(a: Vec3, b: Vec3, c: Vec3) -> Option<(Self, Transform, CustomBounds)> {
+        let (plane, transform) = Self::from_points(a, b, c)?;
+        let (x_axis, _, z_axis) = Self::axes(&transform);
+        // the corners in uv, the same space hits are tested in
+        let [a, b, c] = [a, b, c].map(|point| {
+            let local = point - transform.translation;
+            Vec2::new(local.dot(x_axis), local.dot(z_axis)) / plane.size + 0.5
+        });
+        let bounds = CustomBounds::new(move |uv| {
+            // inside when `uv` is on the same side of all three edges (or on one)
+            let side = |from: Vec2, to: Vec2| (to - from).perp_dot(uv - from);
+            let sides = [side(a, b), side(b, c), side(c, a)];
+            sides.iter().all(|&side| side >= 0.0) || sides.iter().all(|&side| side <= 0.0)
+        });
+        Some((plane, transform, bounds))
+    }
+
+    /// The plane's horizontal axis, normal and vertical axis in world space.
+    ///
+    /// We know the unrotated plane mesh has a normal of Vec3::Y from `impl From<Plane> for Mesh`,
+    /// which means the unrotated plane mesh has horizontal and vertical axes of Vec3::X and
+    /// Vec3::Z: see [`MyPlane::LOCAL_NORMAL`] and friends. If that's not the case for your shape
+    /// then you need to rotate them, which is what [`MyPlane::oriented`] does for planes with a
+    /// [`mesh_rotation`](MyPlane::mesh_rotation).
+    ///
+    /// In debug builds this panics if the rotation isn't normalized or the axes come out
+    /// non-orthonormal, which would otherwise silently skew every uv on the plane.
+    pub fn axes(transform: &Transform) -> (Vec3, Vec3, Vec3) {
+        let axes = (
+            (transform.rotation * Self::LOCAL_TANGENT).normalize(),
+            (transform.rotation * Self::LOCAL_NORMAL).normalize(),
+            (transform.rotation * Self::LOCAL_BITANGENT).normalize(),
+        );
+        debug_assert!(
+            is_unit(transform.rotation.length()) && is_orthonormal(axes),
+            "plane axes {axes:?} aren't orthonormal: is the plane's rotation {:?} normalized?",
+            transform.rotation
+        );
+        axes
+    }
+
+    /// The normal of the plane's front face in world space: the normal from [`MyPlane::axes`],
+    /// or its opposite with [`MyPlane::invert_normal`].
+    pub fn front_normal(&self, transform: &Transform) -> Vec3 {
+        let (_, normal, _) = Self::axes(&self.oriented(transform));
+        if self.invert_normal {
+            -normal
+        } else {
+            normal
+        }
+    }
+
+    /// The world-space point at `uv` on the plane, where (0, 0) to (1, 1) spans the plane.
+    ///
+    /// The inverse of the [`PlaneHit::uv`](crate::PlaneHit::uv) picking reports.
+    pub fn point_at_uv(&self, transform: &Transform, uv: Vec2) -> Vec3 {
+        let (center, x_half, z_half, _) = self.world_frame(transform);
+        let local = (uv - 0.5) * 2.0;
+        center + x_half * local.x + z_half * local.y
+    }
+
+    /// The point within the plane's bounds nearest `point`: its projection onto the plane, moved
+    /// onto the nearest edge or corner if it's outside them.
+    pub fn nearest_point(&self, transform: &Transform, point: Vec3) -> Vec3 {
+        let (center, x_half, z_half, _) = self.world_frame(transform);
+        let local = point - center;
+        // how far along each half-extent, clamped to the edges
+        let along = |half: Vec3| (local.dot(half) / half.length_squared()).clamp(-1.0, 1.0);
+        center + x_half * along(x_half) + z_half * along(z_half)
+    }
+
+    /// The plane's center, the vectors from its center to the middle of its +X and +Z edges, and
+    /// its [`front_normal`](Self::front_normal), all in world space, for layout and snapping code
+    /// that needs them together. The corners are `center ± x_half ± z_half`.
+    ///
+    /// The half extents are stretched by the transform's scale, see [`MyPlane::oriented`].
+    pub fn world_frame(&self, transform: &Transform) -> (Vec3, Vec3, Vec3, Vec3) {
+        let oriented = self.oriented(transform);
+        let (x_axis, _, z_axis) = Self::axes(&oriented);
+        let half = self.size * 0.5;
+        (
+            transform.translation,
+            x_axis * oriented.scale.x * half,
+            z_axis * oriented.scale.z * half,
+            self.front_normal(transform),
+        )
+    }
+
+    /// The plane's side lengths along its uv axes in world units: `size` stretched by the
+    /// transform's scale, see [`MyPlane::oriented`].
+    pub fn world_size(&self, transform: &Transform) -> Vec2 {
+        let scale = self.oriented(transform).scale;
+        Vec2::new(scale.x, scale.z) * self.size
+    }
+
+    /// How far `point` is from the plane's center along its horizontal and vertical axes, in
+    /// world units, as [`PlaneHit::axis_distances`](crate::PlaneHit::axis_distances) measures it.
+    pub fn axis_distances(&self, transform: &Transform, point: Vec3) -> Vec2 {
+        let (x_axis, _, z_axis) = Self::axes(&self.oriented(transform));
+        let local = point - transform.translation;
+        Vec2::new(local.dot(x_axis), local.dot(z_axis))
+    }
+
+    /// The uv of `local`, a point in the plane's local space (see [`MyPlane::to_local`]), as
+    /// picking first measures it, before [`PickSettings::uv_origin`] or a
+    /// [`UvTransform`](crate::UvTransform) remap it: from (0, 0) to (1, 1) across the mesh. For
+    /// a hit, pass its [`local_mesh_point`](crate::PlaneHit::local_mesh_point) to go by where it
+    /// really is on the plane.
+    ///
+    /// [`PickSettings::uv_origin`]: crate::PickSettings::uv_origin
+    pub fn local_uv(&self, local: Vec3) -> Vec2 {
+        Vec2::new(local.x, local.z) / self.size + 0.5
+    }
+
+    /// `point` in the plane's local space, the space its mesh is built in, where the plane spans
+    /// -size/2 to size/2 along X and Z. The inverse of the transform, scale included; along an
+    /// axis scaled to zero the result is zero.
+    pub fn to_local(transform: &Transform, point: Vec3) -> Vec3 {
+        let unscaled = transform.rotation.inverse() * (point - transform.translation);
+        let scale = transform.scale;
+        Vec3::select(scale.cmpeq(Vec3::ZERO), Vec3::ZERO, unscaled / scale)
+    }
+
+    /// The plane's corners in world space, in the order of their uvs: (0, 0), (1, 0), (1, 1),
+    /// (0, 1). That's clockwise when looking at the front of the plane.
+    ///
+    /// Like [`MyPlane::world_frame`], they're stretched by the transform's scale.
+    pub fn corners(&self, transform: &GlobalTransform) -> [Vec3; 4] {
+        let (origin, x_half, z_half, _) = self.world_frame(&transform.compute_transform());
+        [
+            origin - x_half - z_half,
+            origin + x_half - z_half,
+            origin + x_half + z_half,
+            origin - x_half + z_half,
+        ]
+    }
+
+    /// The world-space axis-aligned bounding box of the plane's [`corners`](Self::corners), e.g.
+    /// to put planes into your own spatial structure.
+    ///
+    /// It's flat along any axis the plane is perpendicular to.
+    pub fn aabb(&self, transform: &GlobalTransform) -> Aabb {
+        let corners = self.corners(transform);
+        let min = corners.into_iter().fold(corners[0], Vec3::min);
+        let max = corners.into_iter().fold(corners[0], Vec3::max);
+        Aabb::from_min_max(min, max)
+    }
+}
+
+/// The tolerance of [`is_unit`] and [`is_orthonormal`], loose enough for rotations built up
+/// from many small turns.
+const ORTHONORMAL_EPSILON: f32 = 1e-3;
+
+/// Whether `length` is 1, to within [`ORTHONORMAL_EPSILON`]. False if it's NaN.
+fn is_unit(length: f32) -> bool {
+    (length - 1.0).abs() <= ORTHONORMAL_EPSILON
+}
+
+/// Whether the axes are unit length and perpendicular to each other, to within
+/// [`ORTHONORMAL_EPSILON`]. False if any are NaN.
+fn is_orthonormal((x, y, z): (Vec3, Vec3, Vec3)) -> bool {
+    is_unit(x.length())
+        && is_unit(y.length())
+        && is_unit(z.length())
+        && x.dot(y).abs() <= ORTHONORMAL_EPSILON
+        && y.dot(z).abs() <= ORTHONORMAL_EPSILON
+        && z.dot(x).abs() <= ORTHONORMAL_EPSILON
+}
+
+/// How far the point under the cursor moved across the plane between two rays, e.g. last frame's
+/// and this frame's cursor rays, along the plane's horizontal and vertical axes in world units.
+///
+/// This is the core of dragging something along a plane. The plane is treated as infinite, so a
+/// drag doesn't stop at its edge, and its size doesn't matter. The axes are the ones its uvs run
+/// along, so they follow its [`MyPlane::mesh_rotation`]. Returns `None` if either ray misses the
+/// plane.
+pub fn plane_delta(
+    prev_ray: &Ray,
+    curr_ray: &Ray,
+    transform: &Transform,
+    plane: &MyPlane,
+) -> Option<Vec2> {
+    let (x_axis, _, z_axis) = MyPlane::axes(&plane.oriented(transform));
+    let normal = plane.front_normal(transform);
+    let intersect = |ray: &Ray| {
+        if is_ray_parallel(ray, normal, f32::EPSILON) {
+            return None;
+        }
+        let distance = ray.intersect_plane(transform.translation, normal)?;
+        Some(ray.get_point(distance))
+    };
+    let offset = intersect(curr_ray)? - intersect(prev_ray)?;
+    Some(Vec2::new(offset.dot(x_axis), offset.dot(z_axis)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Face, PlaneHit};
+
+    /// Pick `plane` at `transform` with the ray from `origin` along `direction`.
+    fn click(plane: &MyPlane, transform: &Transform, origin: Vec3, direction: Vec3) -> PlaneHit {
+        let ray = Ray { origin, direction };
+        PlaneHit::compute(
+            ray,
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            transform,
+            plane,
+        )
+        .expect("the ray should cross the plane")
+    }
+
+    fn assert_hit(hit: &PlaneHit, world: Vec3, uv: Vec2, normal: Vec3) {
+        assert!(
+            hit.world.abs_diff_eq(world, 1e-5),
+            "world {} != {world}",
+            hit.world
+        );
+        assert!(hit.uv.abs_diff_eq(uv, 1e-5), "uv {} != {uv}", hit.uv);
+        assert!(
+            hit.normal.abs_diff_eq(normal, 1e-5),
+            "normal {} != {normal}",
+            hit.normal
+        );
+        assert!(hit.inside);
+        assert_eq!(hit.face, Face::Front);
+    }
+
+    #[test]
+    fn x_normal() {
+        let plane = MyPlane::from_mesh_normal(2.0, Vec3::X);
+        let hit = click(
+            &plane,
+            &Transform::IDENTITY,
+            Vec3::new(5.0, 0.5, 0.5),
+            Vec3::NEG_X,
+        );
+        // uv.x runs along -Y and uv.y along +Z
+        assert_hit(
+            &hit,
+            Vec3::new(0.0, 0.5, 0.5),
+            Vec2::new(0.25, 0.75),
+            Vec3::X,
+        );
+    }
+
+    #[test]
+    fn y_normal() {
+        let plane = MyPlane::from_mesh_normal(2.0, Vec3::Y);
+        let hit = click(
+            &plane,
+            &Transform::IDENTITY,
+            Vec3::new(0.5, 5.0, 0.5),
+            Vec3::NEG_Y,
+        );
+        // uv.x runs along +X and uv.y along +Z
+        assert_hit(
+            &hit,
+            Vec3::new(0.5, 0.0, 0.5),
+            Vec2::new(0.75, 0.75),
+            Vec3::Y,
+        );
+    }
+
+    #[test]
+    fn z_normal() {
+        let plane = MyPlane::from_mesh_normal(2.0, Vec3::Z);
+        let hit = click(
+            &plane,
+            &Transform::IDENTITY,
+            Vec3::new(0.5, 0.5, 5.0),
+            Vec3::NEG_Z,
+        );
+        // uv.x runs along +X and uv.y along -Y
+        assert_hit(
+            &hit,
+            Vec3::new(0.5, 0.5, 0.0),
+            Vec2::new(0.75, 0.25),
+            Vec3::Z,
+        );
+    }
+
+    #[test]
+    fn z_normal_rotated_and_moved() {
+        let plane = MyPlane::from_mesh_normal(2.0, Vec3::Z);
+        // a quarter turn about Y takes +Z to +X and +X to -Z
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0)
+            .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2));
+        let hit = click(&plane, &transform, Vec3::new(5.0, 2.5, 3.5), Vec3::NEG_X);
+        // uv.x runs along -Z and uv.y along -Y
+        assert_hit(
+            &hit,
+            Vec3::new(1.0, 2.5, 3.5),
+            Vec2::new(0.25, 0.25),
+            Vec3::X,
+        );
+        assert!(plane
+            .point_at_uv(&transform, hit.uv)
+            .abs_diff_eq(hit.world, 1e-5));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "aren't orthonormal")]
+    fn unnormalized_rotation_panics() {
+        let transform = Transform::from_rotation(Quat::from_xyzw(0.0, 0.0, 0.0, 2.0));
+        MyPlane::axes(&transform);
+    }
+
+    /// A plane of side 2 at (1, 2, 3), turned a quarter about Y, which takes +X to -Z and +Z to
+    /// +X.
+    fn turned() -> (MyPlane, GlobalTransform) {
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0)
+            .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2));
+        (MyPlane::new(2.0), transform.into())
+    }
+
+    #[test]
+    fn corners_of_a_rotated_moved_plane() {
+        let (plane, transform) = turned();
+        let expected = [
+            Vec3::new(0.0, 2.0, 4.0),
+            Vec3::new(0.0, 2.0, 2.0),
+            Vec3::new(2.0, 2.0, 2.0),
+            Vec3::new(2.0, 2.0, 4.0),
+        ];
+        for (corner, expected) in plane.corners(&transform).into_iter().zip(expected) {
+            assert!(corner.abs_diff_eq(expected, 1e-5), "{corner} != {expected}");
+        }
+        // in the order of their uvs
+        let transform = transform.compute_transform();
+        for (corner, uv) in plane.corners(&transform.into()).into_iter().zip([
+            Vec2::ZERO,
+            Vec2::X,
+            Vec2::ONE,
+            Vec2::Y,
+        ]) {
+            assert!(corner.abs_diff_eq(plane.point_at_uv(&transform, uv), 1e-5));
+        }
+    }
+
+    #[test]
+    fn aabb_bounds_the_corners() {
+        let plane = MyPlane::new(2.0);
+        let transform = GlobalTransform::from(
+            Transform::from_xyz(1.0, 2.0, 3.0).with_rotation(Quat::from_euler(
+                EulerRot::YXZ,
+                0.7,
+                0.5,
+                0.0,
+            )),
+        );
+        let corners = plane.corners(&transform);
+        let min = corners.into_iter().reduce(Vec3::min).unwrap();
+        let max = corners.into_iter().reduce(Vec3::max).unwrap();
+        let aabb = plane.aabb(&transform);
+        assert!(Vec3::from(aabb.min()).abs_diff_eq(min, 1e-5));
+        assert!(Vec3::from(aabb.max()).abs_diff_eq(max, 1e-5));
+
+        // flat along Y for a level plane
+        let aabb = plane.aabb(&GlobalTransform::IDENTITY);
+        assert_eq!(aabb.half_extents.y, 0.0);
+    }
+
+    /// A triangle tilted 45 degrees about X, spanning (2s, 2t, 2t) for s, t >= 0 and s + t <= 1.
+    const TRIANGLE: [Vec3; 3] = [
+        Vec3::ZERO,
+        Vec3::new(2.0, 0.0, 0.0),
+        Vec3::new(0.0, 2.0, 2.0),
+    ];
+
+    /// Pick `plane` straight down through (x, _, z).
+    fn click_down(plane: &MyPlane, transform: &Transform, x: f32, z: f32) -> PlaneHit {
+        click(plane, transform, Vec3::new(x, 10.0, z), Vec3::NEG_Y)
+    }
+
+    #[test]
+    fn plane_from_points_covers_them() {
+        let [a, b, c] = TRIANGLE;
+        let (plane, transform) = MyPlane::from_points(a, b, c).unwrap();
+        let normal = plane.front_normal(&transform);
+        assert!(normal.abs_diff_eq(Vec3::new(0.0, 1.0, -1.0).normalize(), 1e-5));
+        for point in TRIANGLE {
+            let hit = click_down(&plane, &transform, point.x, point.z);
+            assert!(
+                hit.world.abs_diff_eq(point, 1e-4),
+                "{} != {point}",
+                hit.world
+            );
+            assert!(hit.inside);
+        }
+        // the horizontal axis runs from `a` to `b`
+        let uv_a = click_down(&plane, &transform, a.x, a.z).uv;
+        let uv_b = click_down(&plane, &transform, b.x, b.z).uv;
+        assert!((uv_b - uv_a).abs_diff_eq(Vec2::new(2.0 / plane.size, 0.0), 1e-5));
+        // no plane through points in a line
+        assert!(MyPlane::from_points(Vec3::ZERO, Vec3::X, Vec3::X * 2.0).is_none());
+    }
+
+    #[test]
+    fn triangle_bounds_pick_only_the_triangle() {
+        let [a, b, c] = TRIANGLE;
+        let (plane, transform, bounds) = MyPlane::from_triangle(a, b, c).unwrap();
+        let inside = click_down(&plane, &transform, 0.5, 0.5);
+        assert!(inside.world.abs_diff_eq(Vec3::splat(0.5), 1e-4));
+        assert!((bounds.0)(inside.uv));
+        // on the plane's square, but past the triangle's long edge
+        let outside = click_down(&plane, &transform, 1.5, 1.5);
+        assert!(outside.inside);
+        assert!(!(bounds.0)(outside.uv));
+    }
+
+    #[test]
+    fn world_frame_of_a_rotated_scaled_plane() {
+        let (plane, transform) = turned();
+        let transform = transform
+            .compute_transform()
+            .with_scale(Vec3::new(3.0, 1.0, 0.5));
+        let (center, x_half, z_half, normal) = plane.world_frame(&transform);
+        assert_eq!(center, Vec3::new(1.0, 2.0, 3.0));
+        assert!(x_half.abs_diff_eq(Vec3::NEG_Z * 3.0, 1e-5));
+        assert!(z_half.abs_diff_eq(Vec3::X * 0.5, 1e-5));
+        assert!(normal.abs_diff_eq(Vec3::Y, 1e-5));
+        // the corners are where `corners` puts them
+        let corners = plane.corners(&transform.into());
+        assert!(corners[2].abs_diff_eq(center + x_half + z_half, 1e-5));
+        // and `to_local` takes them back to the mesh's corners, scale and all
+        let local = MyPlane::to_local(&plane.oriented(&transform), corners[2]);
+        assert!(local.abs_diff_eq(Vec3::new(1.0, 0.0, 1.0), 1e-5));
+    }
+
+    #[test]
+    fn plane_delta_follows_the_mesh_rotation() {
+        // a `shape::Quad`, facing +Z, so uv.x runs along +X and uv.y along -Y
+        let plane = MyPlane::from_mesh_normal(2.0, Vec3::Z);
+        let transform = Transform::from_xyz(0.0, 0.0, -1.0);
+        let prev = Ray {
+            origin: Vec3::new(0.0, 0.0, 5.0),
+            direction: Vec3::NEG_Z,
+        };
+        let curr = Ray {
+            origin: Vec3::new(0.5, 1.0, 5.0),
+            ..prev
+        };
+        let delta = plane_delta(&prev, &curr, &transform, &plane).unwrap();
+        assert!(delta.abs_diff_eq(Vec2::new(0.5, -1.0), 1e-5));
+        // running along the plane, so never crossing it
+        let along = Ray {
+            direction: Vec3::X,
+            ..prev
+        };
+        assert!(plane_delta(&prev, &along, &transform, &plane).is_none());
+    }
+}
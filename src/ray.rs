@@ -0,0 +1,183 @@
+use bevy::{prelude::*, render::primitives::Aabb};
+
+/// Extra intersection tests for [`Ray`].
+pub trait RayExt {
+    /// Distance along the ray at which it enters `aabb`, using the slab method.
+    ///
+    /// Returns `Some(0.0)` if the ray starts inside the box, and `None` if it misses the box or
+    /// the box is entirely behind the ray.
+    fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32>;
+
+    /// How close the ray's line passes to `point`, and the distance along the ray where it's
+    /// closest, e.g. for aim assist towards a plane's center, its transform's translation, even
+    /// when the ray misses the plane. The distance along the ray is negative if the closest
+    /// point is behind the ray origin, and is in units of the ray direction's length.
+    fn closest_approach(&self, point: Vec3) -> (f32, f32);
+}
+
+impl RayExt for Ray {
+    fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        let min = Vec3::from(aabb.min());
+        let max = Vec3::from(aabb.max());
+        // an axis-parallel direction gives an infinite inverse, which the min/max below handle
+        // as long as the origin isn't exactly on a slab boundary
+        let inverse_direction = self.direction.recip();
+        let t1 = (min - self.origin) * inverse_direction;
+        let t2 = (max - self.origin) * inverse_direction;
+        let t_enter = t1.min(t2).max_element();
+        let t_exit = t1.max(t2).min_element();
+        (t_enter <= t_exit && t_exit >= 0.0).then_some(t_enter.max(0.0))
+    }
+
+    fn closest_approach(&self, point: Vec3) -> (f32, f32) {
+        // project the offset to the point onto the direction
+        let along = (point - self.origin).dot(self.direction) / self.direction.length_squared();
+        (self.get_point(along).distance(point), along)
+    }
+}
+
+/// The ray from `camera` through `position` in its viewport, in logical pixels, or `None` if the
+/// camera has no viewport yet or the position can't be unprojected.
+///
+/// Every pick builds its rays with this, so rays you build yourself, e.g. for several crosshairs
+/// or a virtual cursor, pick exactly like the real cursor through
+/// [`PlanePicker::pick_ray`](crate::PlanePicker::pick_ray). It goes through
+/// [`Camera::viewport_to_world`], so it works with any projection.
+pub fn viewport_ray(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    position: Vec2,
+) -> Option<Ray> {
+    camera.viewport_to_world(camera_transform, position)
+}
+
+/// Whether `ray` runs (nearly) parallel to a plane with `normal`, i.e. the cosine of the angle
+/// between the ray and the normal is within `epsilon` of 0. Neither vector needs to be normalized.
+///
+/// Picking rejects such rays with an `epsilon` of [`f32::EPSILON`], matching
+/// [`Ray::intersect_plane`]. For your own tests, something around `1e-4` (rays within about
+/// 0.006 degrees of the plane) is a good starting point; grazing hits closer than that land so far
+/// away, and are so sensitive to the cursor, that they're rarely useful.
+pub fn is_ray_parallel(ray: &Ray, normal: Vec3, epsilon: f32) -> bool {
+    ray.direction
+        .normalize_or_zero()
+        .dot(normal.normalize_or_zero())
+        .abs()
+        <= epsilon
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{bare_app, screen_point, spawn_camera, window_center};
+
+    fn ray(origin: Vec3, direction: Vec3) -> Ray {
+        Ray { origin, direction }
+    }
+
+    fn unit_box() -> Aabb {
+        Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(1.0))
+    }
+
+    #[test]
+    fn intersect_aabb_hit() {
+        let hit = ray(Vec3::new(-5.0, 0.5, 0.0), Vec3::X).intersect_aabb(&unit_box());
+        assert_eq!(hit, Some(4.0));
+        // diagonally, entering at a corner
+        let hit = ray(Vec3::splat(-3.0), Vec3::ONE.normalize()).intersect_aabb(&unit_box());
+        assert!((hit.unwrap() - 2.0 * 3f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn intersect_aabb_miss() {
+        // passes beside the box
+        assert_eq!(
+            ray(Vec3::new(-5.0, 2.0, 0.0), Vec3::X).intersect_aabb(&unit_box()),
+            None
+        );
+        // points away from it
+        assert_eq!(
+            ray(Vec3::new(-5.0, 0.0, 0.0), Vec3::NEG_X).intersect_aabb(&unit_box()),
+            None
+        );
+    }
+
+    #[test]
+    fn intersect_aabb_inside() {
+        let hit = ray(Vec3::new(0.5, 0.0, 0.0), Vec3::NEG_Z).intersect_aabb(&unit_box());
+        assert_eq!(hit, Some(0.0));
+    }
+
+    #[test]
+    fn parallel_rays() {
+        // along the plane, and with unnormalized vectors
+        assert!(is_ray_parallel(&ray(Vec3::ZERO, Vec3::X), Vec3::Y, 1e-4));
+        assert!(is_ray_parallel(
+            &ray(Vec3::ZERO, Vec3::new(3.0, 0.0, 4.0)),
+            Vec3::Y * 2.0,
+            1e-4
+        ));
+        // grazing, inside the recommended epsilon but not the one picking uses
+        let grazing = Vec3::new(1.0, 1e-5, 0.0);
+        assert!(is_ray_parallel(&ray(Vec3::ZERO, grazing), Vec3::Y, 1e-4));
+        assert!(!is_ray_parallel(
+            &ray(Vec3::ZERO, grazing),
+            Vec3::Y,
+            f32::EPSILON
+        ));
+    }
+
+    #[test]
+    fn perpendicular_rays_arent_parallel() {
+        assert!(!is_ray_parallel(
+            &ray(Vec3::ZERO, Vec3::NEG_Y),
+            Vec3::Y,
+            1e-4
+        ));
+        assert!(!is_ray_parallel(&ray(Vec3::ZERO, Vec3::Y), Vec3::Y, 1e-4));
+        assert!(!is_ray_parallel(
+            &ray(Vec3::ZERO, Vec3::new(1.0, -1.0, 0.0)),
+            Vec3::Y,
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn viewport_ray_through_a_known_camera() {
+        let mut app = bare_app();
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO);
+        app.update();
+        let center = window_center(&mut app);
+        let off_center = screen_point(&mut app, camera, Vec3::new(1.0, 0.5, -2.0));
+        let (camera, transform) = app
+            .world
+            .query::<(&Camera, &GlobalTransform)>()
+            .single(&app.world);
+
+        let ray = viewport_ray(camera, transform, center).unwrap();
+        // from the near plane, 0.1 in by default, straight ahead
+        assert!(ray.origin.abs_diff_eq(Vec3::new(0.0, 0.0, 4.9), 1e-4));
+        assert!(ray.direction.abs_diff_eq(Vec3::NEG_Z, 1e-5));
+
+        let ray = viewport_ray(camera, transform, off_center).unwrap();
+        let (distance, _) = ray.closest_approach(Vec3::new(1.0, 0.5, -2.0));
+        assert!(distance < 1e-3);
+    }
+
+    #[test]
+    fn closest_approach_to_a_plane_center() {
+        let through = ray(Vec3::new(1.0, 5.0, 2.0), Vec3::NEG_Y);
+        let (distance, along) = through.closest_approach(Vec3::new(1.0, 0.0, 2.0));
+        assert!(distance.abs() < 1e-6);
+        assert_eq!(along, 5.0);
+        // passing beside it, and in units of an unnormalized direction
+        let beside = ray(Vec3::new(0.0, 5.0, 0.0), Vec3::NEG_Y * 2.0);
+        assert_eq!(
+            beside.closest_approach(Vec3::new(3.0, 1.0, 4.0)),
+            (5.0, 2.0)
+        );
+        // behind the origin
+        let (_, along) = through.closest_approach(Vec3::new(1.0, 8.0, 2.0));
+        assert_eq!(along, -3.0);
+    }
+}
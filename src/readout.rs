@@ -0,0 +1,122 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::HoverUv;
+
+/// Insert this to show the coordinates under the cursor as text next to the hovered point, for
+/// live feedback while building a scene. The text is hidden while nothing is hovered.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{CoordReadout, IntersectPlanePlugin, ReadoutCoords};
+/// App::new()
+///     .add_plugins((DefaultPlugins, IntersectPlanePlugin::default()))
+///     .insert_resource(CoordReadout {
+///         coords: ReadoutCoords::World,
+///         precision: 1,
+///         ..default()
+///     });
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct CoordReadout {
+    /// Which coordinates are shown
+    pub coords: ReadoutCoords,
+    /// How many decimal places are shown
+    pub precision: usize,
+    pub font_size: f32,
+    pub color: Color,
+    /// Where the text's top left corner is from the hovered point, in logical pixels, so it
+    /// doesn't sit under the cursor
+    pub offset: Vec2,
+}
+
+impl Default for CoordReadout {
+    fn default() -> Self {
+        Self {
+            coords: ReadoutCoords::default(),
+            precision: 2,
+            font_size: 16.0,
+            color: Color::WHITE,
+            offset: Vec2::new(12.0, 12.0),
+        }
+    }
+}
+
+/// The coordinates a [`CoordReadout`] shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadoutCoords {
+    /// [`PlaneHit::uv`](crate::PlaneHit::uv)
+    #[default]
+    Uv,
+    /// [`PlaneHit::coords`](crate::PlaneHit::coords), in
+    /// [`PickSettings::coord_space`](crate::PickSettings::coord_space)
+    Coords,
+    /// [`PlaneHit::world`](crate::PlaneHit::world)
+    World,
+}
+
+/// Tags the text of the [`CoordReadout`] for one plugin instance.
+#[derive(Component)]
+pub(crate) struct ReadoutText<C, P>(PhantomData<fn() -> (C, P)>);
+
+pub(crate) fn update_coord_readout<C: Component, P: Component>(
+    mut commands: Commands,
+    readout: Res<CoordReadout>,
+    hover: Res<HoverUv<C, P>>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<C>>,
+    mut q_text: Query<(&mut Text, &mut Style), With<ReadoutText<C, P>>>,
+) {
+    let Ok((mut text, mut style)) = q_text.get_single_mut() else {
+        commands.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: readout.font_size,
+                    color: readout.color,
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                ..default()
+            }),
+            ReadoutText::<C, P>(PhantomData),
+        ));
+        return;
+    };
+    let position =
+        hover
+            .hit
+            .zip(q_camera.get_single().ok())
+            .and_then(|(hit, (camera, camera_transform))| {
+                let pixel = camera.world_to_viewport(camera_transform, hit.world)?;
+                Some((hit, pixel))
+            });
+    let Some((hit, pixel)) = position else {
+        if style.display != Display::None {
+            style.display = Display::None;
+        }
+        return;
+    };
+    let precision = readout.precision;
+    text.sections[0].value = match readout.coords {
+        ReadoutCoords::Uv => format!("{:.*}, {:.*}", precision, hit.uv.x, precision, hit.uv.y),
+        ReadoutCoords::Coords => {
+            format!(
+                "{:.*}, {:.*}",
+                precision, hit.coords.x, precision, hit.coords.y
+            )
+        }
+        ReadoutCoords::World => format!(
+            "{:.*}, {:.*}, {:.*}",
+            precision, hit.world.x, precision, hit.world.y, precision, hit.world.z
+        ),
+    };
+    text.sections[0].style.font_size = readout.font_size;
+    text.sections[0].style.color = readout.color;
+    style.left = Val::Px(pixel.x + readout.offset.x);
+    style.top = Val::Px(pixel.y + readout.offset.y);
+    style.display = Display::Flex;
+}
@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+
+use crate::{viewport_ray, MyPlane, PlaneHit};
+
+/// The viewport-space rectangle, in logical pixels, enclosing a plane as seen from `camera`.
+///
+/// An edge running from a corner in front of the camera to one behind it is cut where it leaves
+/// the view, so a plane that is only partly in front of the camera gets the bounds of just that
+/// part. Returns `None` if the whole plane is behind the camera (or otherwise can't be
+/// projected). The rectangle isn't clamped to the viewport.
+pub fn plane_screen_rect(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    plane: &MyPlane,
+    plane_transform: &GlobalTransform,
+) -> Option<Rect> {
+    let corners = plane.corners(plane_transform);
+    let project = |point| camera.world_to_viewport(camera_transform, point);
+
+    let mut points = Vec::with_capacity(8);
+    for (i, &corner) in corners.iter().enumerate() {
+        let next = corners[(i + 1) % corners.len()];
+        match (project(corner), project(next)) {
+            (Some(start), Some(_)) => points.push(start),
+            (Some(start), None) => {
+                points.push(start);
+                points.extend(last_visible(corner, next, project));
+            }
+            (None, Some(_)) => points.extend(last_visible(next, corner, project)),
+            (None, None) => {}
+        }
+    }
+
+    let (first, rest) = points.split_first()?;
+    Some(
+        rest.iter()
+            .fold(Rect::from_corners(*first, *first), |rect, point| {
+                rect.union_point(*point)
+            }),
+    )
+}
+
+/// Roughly how much of the viewport a plane covers, from 0 to 1, e.g. to only label planes big
+/// enough to read a label on: the area of its [`plane_screen_rect`], clipped to the viewport, over
+/// the viewport's area. 0 for a plane that's off screen or behind the camera, or if the camera's
+/// viewport isn't known yet.
+///
+/// It's the area of the bounds, so a plane seen at an angle covers less than this.
+pub fn plane_screen_coverage(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    plane: &MyPlane,
+    plane_transform: &GlobalTransform,
+) -> f32 {
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return 0.0;
+    };
+    let viewport_area = viewport.x * viewport.y;
+    let Some(rect) = plane_screen_rect(camera, camera_transform, plane, plane_transform) else {
+        return 0.0;
+    };
+    if viewport_area <= 0.0 {
+        return 0.0;
+    }
+    let visible = rect.intersect(Rect::from_corners(Vec2::ZERO, viewport));
+    (visible.width() * visible.height() / viewport_area).clamp(0.0, 1.0)
+}
+
+/// Whether two planes' [`plane_screen_rect`]s overlap as seen from `camera`, e.g. to stack their
+/// tooltips. A plane entirely behind the camera overlaps nothing; one partly behind it counts by
+/// the part in front. Rectangles that only touch along an edge don't overlap.
+///
+/// The rectangles are bounds, so planes seen at an angle may count as overlapping when only their
+/// bounds do.
+pub fn planes_overlap_on_screen(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    (a, a_transform): (&MyPlane, &GlobalTransform),
+    (b, b_transform): (&MyPlane, &GlobalTransform),
+) -> bool {
+    let rect = |plane, transform| plane_screen_rect(camera, camera_transform, plane, transform);
+    let (Some(a), Some(b)) = (rect(a, a_transform), rect(b, b_transform)) else {
+        return false;
+    };
+    let overlap = a.intersect(b);
+    overlap.width() > 0.0 && overlap.height() > 0.0
+}
+
+/// Where each of `points`, in logical viewport pixels, lands on the plane as seen from `camera`,
+/// e.g. to make a freehand stroke stick to the surface. This is a batched form of a single
+/// pick against one plane.
+///
+/// Each point is `None` where its ray misses the plane or lands outside its bounds.
+pub fn project_screen_path(
+    points: &[Vec2],
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    plane_transform: &GlobalTransform,
+    plane: &MyPlane,
+) -> Vec<Option<Vec3>> {
+    let plane_transform = plane_transform.compute_transform();
+    points
+        .iter()
+        .map(|&point| {
+            let ray = viewport_ray(camera, camera_transform, point)?;
+            let hit = PlaneHit::compute(
+                ray,
+                Entity::PLACEHOLDER,
+                Entity::PLACEHOLDER,
+                &plane_transform,
+                plane,
+            )?;
+            hit.inside.then_some(hit.world)
+        })
+        .collect()
+}
+
+/// Bisect the segment from the projectable `visible` to the unprojectable `hidden` for the
+/// furthest point that still projects, i.e. where the segment leaves the view.
+fn last_visible(
+    visible: Vec3,
+    hidden: Vec3,
+    project: impl Fn(Vec3) -> Option<Vec2>,
+) -> Option<Vec2> {
+    const ITERATIONS: usize = 16;
+    let (mut inside, mut outside) = (0.0, 1.0);
+    for _ in 0..ITERATIONS {
+        let t = (inside + outside) * 0.5;
+        if project(visible.lerp(hidden, t)).is_some() {
+            inside = t;
+        } else {
+            outside = t;
+        }
+    }
+    project(visible.lerp(hidden, inside))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{headless_app, screen_point, spawn_camera};
+
+    /// A camera at `position` looking at the origin, sized to the window, and where it sees a
+    /// few ground points.
+    fn looking_down(position: Vec3, points: &[Vec3]) -> (Camera, GlobalTransform, Vec<Vec2>) {
+        let mut app = headless_app();
+        let camera = spawn_camera(&mut app, position, Vec3::ZERO);
+        app.update();
+        let points = points
+            .iter()
+            .map(|&point| screen_point(&mut app, camera, point))
+            .collect();
+        let entity = app.world.entity(camera);
+        let (camera, transform) = (entity.get::<Camera>(), entity.get::<GlobalTransform>());
+        (camera.unwrap().clone(), *transform.unwrap(), points)
+    }
+
+    #[test]
+    fn a_path_crossing_the_edge() {
+        let path = [
+            Vec3::ZERO,
+            Vec3::new(0.5, 0.0, 0.2),
+            Vec3::new(1.5, 0.0, 0.4),
+            Vec3::new(0.9, 0.0, 0.6),
+        ];
+        let (camera, camera_transform, points) = looking_down(Vec3::new(0.0, 10.0, 0.1), &path);
+        let projected = project_screen_path(
+            &points,
+            &camera,
+            &camera_transform,
+            &GlobalTransform::IDENTITY,
+            &MyPlane::new(2.0),
+        );
+        assert_eq!(projected.len(), path.len());
+        for (projected, point) in projected.into_iter().zip(path) {
+            // off the plane past its +X edge
+            if point.x > 1.0 {
+                assert_eq!(projected, None);
+            } else {
+                assert!(projected.unwrap().abs_diff_eq(point, 1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn planes_overlap_by_their_screen_bounds() {
+        let (camera, camera_transform, _) = looking_down(Vec3::new(0.0, 10.0, 0.1), &[]);
+        let plane = MyPlane::new(2.0);
+        let at = |x: f32, y: f32| GlobalTransform::from_xyz(x, y, 0.0);
+        let overlap = |a: &GlobalTransform, b: &GlobalTransform| {
+            planes_overlap_on_screen(&camera, &camera_transform, (&plane, a), (&plane, b))
+        };
+
+        assert!(overlap(&at(0.0, 0.0), &at(1.5, 0.0)));
+        assert!(!overlap(&at(0.0, 0.0), &at(3.0, 0.0)));
+        // apart in the world, but one above the other on screen
+        assert!(overlap(&at(0.0, 0.0), &at(0.5, 5.0)));
+        // behind the camera
+        assert!(!overlap(&at(0.0, 0.0), &at(0.0, 20.0)));
+    }
+
+    #[test]
+    fn coverage_shrinks_with_distance() {
+        let plane = MyPlane::new(2.0);
+        let coverage = |height: f32| {
+            let (camera, camera_transform, _) = looking_down(Vec3::new(0.0, height, 0.1), &[]);
+            plane_screen_coverage(
+                &camera,
+                &camera_transform,
+                &plane,
+                &GlobalTransform::IDENTITY,
+            )
+        };
+        let (near, far) = (coverage(5.0), coverage(10.0));
+        assert!(near > 0.0 && near < 1.0);
+        // twice as far covers about a quarter of the area
+        assert!((far / near - 0.25).abs() < 0.01, "{near} then {far}");
+        // close enough to fill the view
+        assert_eq!(coverage(0.5), 1.0);
+    }
+}
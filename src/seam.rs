@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::{HoverUv, MyPlane, PickingConfig};
+
+/// How close, in world units, another plane's edge has to come to the hovered plane's for the
+/// two to count as sharing it.
+const ADJACENCY_EPSILON: f32 = 1e-3;
+
+/// Sent every frame the hovered point is within
+/// [`PickSettings::blend_seam_distance`](crate::PickSettings::blend_seam_distance) of an edge
+/// the hovered plane shares with another plane, e.g. for a tool blending heights or textures
+/// across the seam between two tiles.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct BlendHoverEvent {
+    /// The camera the hover was picked through, which identifies the plugin instance
+    pub camera: Entity,
+    /// The hovered plane
+    pub entity: Entity,
+    /// The plane on the other side of the nearest edge
+    pub neighbor: Entity,
+    /// How much of the blend goes to `neighbor`: 0.5 on the seam itself, falling to 0 at
+    /// `blend_seam_distance` from it. The hovered plane gets the rest.
+    pub neighbor_weight: f32,
+    /// The hovered point in world space
+    pub world: Vec3,
+}
+
+pub(crate) fn send_blend_hover<C: Component, P: Component>(
+    config: Res<PickingConfig<C, P>>,
+    hover_uv: Res<HoverUv<C, P>>,
+    q_plane: Query<(Entity, &GlobalTransform, &MyPlane), With<P>>,
+    mut events: EventWriter<BlendHoverEvent>,
+) {
+    let (Some(max_distance), Some(hit)) = (config.blend_seam_distance, hover_uv.hit) else {
+        return;
+    };
+    let Ok((_, transform, plane)) = q_plane.get(hit.entity) else {
+        return;
+    };
+    // the nearest edge, and where on it the hit is closest to, in world units
+    let transform = transform.compute_transform();
+    let size = plane.world_size(&transform);
+    let half = size * 0.5;
+    let local = hit.axis_distances;
+    // never negative, for hits counted as inside just past the edge by `pick_tolerance`
+    let to_edge = (half - local.abs()).max(Vec2::ZERO);
+    let mut on_edge = local;
+    let distance = if to_edge.x < to_edge.y {
+        on_edge.x = half.x.copysign(local.x);
+        to_edge.x
+    } else {
+        on_edge.y = half.y.copysign(local.y);
+        to_edge.y
+    };
+    if distance > max_distance {
+        return;
+    }
+    let edge_point = plane.point_at_uv(&transform, on_edge / size + 0.5);
+    let neighbor = q_plane
+        .iter()
+        .filter(|&(entity, ..)| entity != hit.entity)
+        .find(|(_, transform, other)| {
+            other
+                .nearest_point(&transform.compute_transform(), edge_point)
+                .distance(edge_point)
+                <= ADJACENCY_EPSILON
+        });
+    if let Some((neighbor, ..)) = neighbor {
+        events.send(BlendHoverEvent {
+            camera: hit.camera,
+            entity: hit.entity,
+            neighbor,
+            neighbor_weight: 0.5 * (1.0 - distance / max_distance.max(f32::EPSILON)),
+            world: hit.world,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{app_with, events, move_cursor, screen_point, spawn_camera, spawn_plane},
+        IntersectPlanePlugin,
+    };
+
+    #[test]
+    fn hovering_near_a_shared_edge_blends_the_neighbors() {
+        let plugin = IntersectPlanePlugin::default().blend_seam_distance(Some(0.5));
+        let mut app = app_with(plugin);
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let left = spawn_plane(&mut app, 2.0, Transform::from_xyz(-1.0, 0.0, 0.0));
+        let right = spawn_plane(&mut app, 2.0, Transform::from_xyz(1.0, 0.0, 0.0));
+        app.update();
+        let mut hover = |x: f32| {
+            let position = screen_point(&mut app, camera, Vec3::new(x, 0.0, 0.0));
+            move_cursor(&mut app, Some(position));
+            // twice, so only this position's events are left
+            app.update();
+            app.update();
+            events::<BlendHoverEvent>(&app)
+                .into_iter()
+                .map(|event| (event.entity, event.neighbor, event.neighbor_weight))
+                .collect::<Vec<_>>()
+        };
+
+        // near the left tile's outer edge, which it shares with nothing, and too far from the seam
+        assert_eq!(hover(-1.9), []);
+        assert_eq!(hover(-1.2), []);
+        let [(entity, neighbor, weight), _] = hover(-0.25)[..] else {
+            panic!("a blend each frame");
+        };
+        assert_eq!((entity, neighbor), (left, right));
+        assert!((weight - 0.25).abs() < 1e-3, "{weight}");
+        let [(entity, neighbor, weight), _] = hover(0.1)[..] else {
+            panic!("a blend each frame");
+        };
+        assert_eq!((entity, neighbor), (right, left));
+        assert!((weight - 0.4).abs() < 1e-3, "{weight}");
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    picking::{apply_mode, ClickInput},
+    PlaneHit, PlanePicker,
+};
+
+/// A recording of clicks: each one's cursor ray and what it hit, which can be saved, e.g. with a
+/// bug report, and replayed against the same scene to check it picks the same. Needs the `serde`
+/// feature.
+///
+/// Record one by inserting a [`PickRecorder`], and check a replay with
+/// [`PickSession::replay`]:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{PickSession, PlanePicker};
+/// fn check_replay(picker: PlanePicker) {
+///     let session = PickSession::from_ron(&std::fs::read_to_string("session.ron").unwrap())
+///         .unwrap();
+///     assert_eq!(session.replay(&picker), session.picks);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PickSession {
+    pub picks: Vec<RecordedPick>,
+}
+
+/// A click in a [`PickSession`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedPick {
+    /// [`Time::elapsed_seconds_f64`] at the click
+    pub time: f64,
+    /// The camera the click picked through, which identifies the plugin instance
+    pub camera: Entity,
+    /// The cursor ray
+    pub ray: Ray,
+    /// What the click hit, front to back
+    pub hits: Vec<RecordedHit>,
+}
+
+/// The parts of a [`PlaneHit`] a [`PickSession`] keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedHit {
+    pub entity: Entity,
+    pub world: Vec3,
+    pub uv: Vec2,
+    pub inside: bool,
+    pub distance: f32,
+}
+
+impl From<&PlaneHit> for RecordedHit {
+    fn from(hit: &PlaneHit) -> Self {
+        Self {
+            entity: hit.entity,
+            world: hit.world,
+            uv: hit.uv,
+            inside: hit.inside,
+            distance: hit.distance,
+        }
+    }
+}
+
+impl PickSession {
+    /// The session as RON, e.g. to write to a file.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, default())
+    }
+
+    /// Read a session written by [`PickSession::to_ron`].
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Pick each recorded click's ray again through `picker`, returning the clicks as they'd be
+    /// recorded now, to compare with [`PickSession::picks`]. Only the clicks made through
+    /// `picker`'s camera are replayed.
+    ///
+    /// Entities are compared as they are, so replay against the scene spawned the same way.
+    pub fn replay<C: Component, P: Component>(
+        &self,
+        picker: &PlanePicker<C, P>,
+    ) -> Vec<RecordedPick> {
+        self.picks
+            .iter()
+            .filter(|pick| picker.camera() == Some(pick.camera))
+            .map(|pick| RecordedPick {
+                hits: click_hits(picker, pick.ray),
+                ..pick.clone()
+            })
+            .collect()
+    }
+}
+
+/// Records every click, and tap, into a [`PickSession`] while it's inserted. Needs the `serde`
+/// feature.
+///
+/// Clicks are recorded as they're picked, before any
+/// [`ClickCooldown`](crate::ClickCooldown), and only for [`MyPlane`](crate::MyPlane)s and
+/// analytic planes: surfaces added with
+/// [`register_pickable`](crate::RegisterPickable::register_pickable) aren't recorded.
+#[derive(Resource, Clone, Debug, Default, Deref, DerefMut)]
+pub struct PickRecorder(pub PickSession);
+
+/// What a click along `ray` hits, as [`PickSession`]s record it.
+fn click_hits<C: Component, P: Component>(
+    picker: &PlanePicker<C, P>,
+    ray: Ray,
+) -> Vec<RecordedHit> {
+    let config = picker.config();
+    let mut hits = picker.pick_ray(ray);
+    apply_mode(config.mode, config, ray, &mut hits);
+    hits.iter()
+        .filter(|hit| config.emit_outside_hits || hit.inside)
+        .map(RecordedHit::from)
+        .collect()
+}
+
+pub(crate) fn record_picks<C: Component, P: Component>(
+    picker: PlanePicker<C, P>,
+    input: ClickInput,
+    time: Res<Time>,
+    mut recorder: ResMut<PickRecorder>,
+) {
+    let Some(pointer) = input.click(picker.config()) else {
+        return;
+    };
+    let (Some(camera), Some(ray)) = (picker.camera(), picker.pointer_ray(pointer)) else {
+        return;
+    };
+    let hits = click_hits(&picker, ray);
+    recorder.picks.push(RecordedPick {
+        time: time.elapsed_seconds_f64(),
+        camera,
+        ray,
+        hits,
+    });
+}
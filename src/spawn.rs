@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::MyPlane;
+
+/// Spawn `rows` by `cols` pickable square planes of side `cell_size` lying side by side on the
+/// XZ plane, e.g. for tile maps, demos and stress tests. Columns run along +X and rows along +Z
+/// from `origin`, the outer corner of the first cell, and all the planes share one mesh.
+///
+/// `material` gives each cell its material from its row and column. Returns the entities laid
+/// out the same way, indexed by row then column.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::spawn_plane_grid;
+/// fn setup(
+///     mut commands: Commands,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     mut materials: ResMut<Assets<StandardMaterial>>,
+/// ) {
+///     let light = materials.add(Color::WHITE.into());
+///     let dark = materials.add(Color::BLACK.into());
+///     // a checkerboard
+///     spawn_plane_grid(&mut commands, &mut meshes, 8, 8, 1.0, Vec3::ZERO, |row, col| {
+///         if (row + col) % 2 == 0 {
+///             light.clone()
+///         } else {
+///             dark.clone()
+///         }
+///     });
+/// }
+/// ```
+pub fn spawn_plane_grid(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    rows: usize,
+    cols: usize,
+    cell_size: f32,
+    origin: Vec3,
+    mut material: impl FnMut(usize, usize) -> Handle<StandardMaterial>,
+) -> Vec<Vec<Entity>> {
+    let plane = MyPlane::new(cell_size);
+    let mesh = meshes.add(plane.to_plane().into());
+    (0..rows)
+        .map(|row| {
+            (0..cols)
+                .map(|col| {
+                    // planes are centered on their transform
+                    let offset = Vec3::new(col as f32 + 0.5, 0.0, row as f32 + 0.5) * cell_size;
+                    commands
+                        .spawn((
+                            plane,
+                            PbrBundle {
+                                mesh: mesh.clone(),
+                                material: material(row, col),
+                                transform: Transform::from_translation(origin + offset),
+                                ..default()
+                            },
+                        ))
+                        .id()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{bare_app, run};
+
+    #[test]
+    fn grids_lay_cells_out_by_row_and_column() {
+        let mut app = bare_app();
+        let origin = Vec3::new(-1.0, 0.5, 2.0);
+        let grid = run(
+            &mut app,
+            move |mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>| {
+                spawn_plane_grid(&mut commands, &mut meshes, 2, 3, 2.0, origin, |_, _| {
+                    Handle::default()
+                })
+            },
+        );
+
+        assert_eq!(grid.len(), 2);
+        assert!(grid.iter().all(|row| row.len() == 3));
+        let mut planes = app.world.query::<(&MyPlane, &Transform)>();
+        assert_eq!(planes.iter(&app.world).count(), 6);
+        for (row, cells) in grid.iter().enumerate() {
+            for (col, &cell) in cells.iter().enumerate() {
+                let (plane, transform) = planes.get(&app.world, cell).unwrap();
+                assert_eq!(plane.size, 2.0);
+                let center =
+                    origin + Vec3::new(col as f32 * 2.0 + 1.0, 0.0, row as f32 * 2.0 + 1.0);
+                assert_eq!(transform.translation, center, "row {row}, column {col}");
+            }
+        }
+    }
+}
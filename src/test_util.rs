@@ -0,0 +1,192 @@
+//! Helpers for the unit tests: a headless app running the default plugin instance, and the
+//! window, camera and planes to pick with.
+
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::MouseButtonInput,
+        touch::{TouchInput, TouchPhase},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    render::camera::{camera_system, ManualTextureViews},
+    window::PrimaryWindow,
+};
+
+use crate::{IntersectPlanePlugin, MainCamera, MyPlane};
+
+/// An app with the input, assets and primary window picking reads, and the system that sizes
+/// cameras to their window, but no renderer. Add the plugin instances to test.
+pub(crate) fn bare_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        TransformPlugin,
+        WindowPlugin::default(),
+    ))
+    .add_asset::<Mesh>()
+    .add_asset::<StandardMaterial>()
+    .add_asset::<Image>()
+    .init_resource::<ManualTextureViews>()
+    .add_systems(PreUpdate, camera_system::<Projection>);
+    app
+}
+
+/// [`bare_app`] with the default [`IntersectPlanePlugin`].
+pub(crate) fn headless_app() -> App {
+    app_with(IntersectPlanePlugin::default())
+}
+
+/// [`bare_app`] with `plugin`, e.g. the default instance with some settings changed.
+pub(crate) fn app_with(plugin: IntersectPlanePlugin) -> App {
+    let mut app = bare_app();
+    app.add_plugins(plugin);
+    app
+}
+
+/// Spawn a camera with `marker` at `position` looking at `target`, rendering to the primary
+/// window.
+pub(crate) fn spawn_camera_with(
+    app: &mut App,
+    marker: impl Component,
+    position: Vec3,
+    target: Vec3,
+) -> Entity {
+    let transform = Transform::from_translation(position).looking_at(target, Vec3::Y);
+    app.world
+        .spawn((
+            Camera::default(),
+            Projection::default(),
+            transform,
+            GlobalTransform::from(transform),
+            marker,
+        ))
+        .id()
+}
+
+/// Spawn a [`MainCamera`] at `position` looking at `target`.
+pub(crate) fn spawn_camera(app: &mut App, position: Vec3, target: Vec3) -> Entity {
+    spawn_camera_with(app, MainCamera, position, target)
+}
+
+/// Spawn a plane of side `size` at `transform`, picked by the instances for `marker`.
+pub(crate) fn spawn_plane_with(
+    app: &mut App,
+    marker: impl Component,
+    size: f32,
+    transform: Transform,
+) -> Entity {
+    app.world
+        .spawn((
+            MyPlane::new(size),
+            transform,
+            GlobalTransform::from(transform),
+            marker,
+        ))
+        .id()
+}
+
+/// Spawn a [`MyPlane`] of side `size` at `transform`.
+pub(crate) fn spawn_plane(app: &mut App, size: f32, transform: Transform) -> Entity {
+    app.world
+        .spawn((
+            MyPlane::new(size),
+            transform,
+            GlobalTransform::from(transform),
+        ))
+        .id()
+}
+
+/// The window [`bare_app`] opens.
+pub(crate) fn primary_window(app: &mut App) -> Entity {
+    app.world
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .single(&app.world)
+}
+
+/// Put the cursor at `position` in the primary window, in logical pixels, or take it out of the
+/// window.
+pub(crate) fn move_cursor(app: &mut App, position: Option<Vec2>) {
+    let window = primary_window(app);
+    app.world
+        .get_mut::<Window>(window)
+        .unwrap()
+        .set_cursor_position(position);
+}
+
+/// The middle of the primary window, in logical pixels, where a camera looks.
+pub(crate) fn window_center(app: &mut App) -> Vec2 {
+    let window = primary_window(app);
+    let window = app.world.get::<Window>(window).unwrap();
+    Vec2::new(window.width(), window.height()) / 2.0
+}
+
+/// Where `camera` sees `point`, in logical pixels. Only known once the app has updated, and the
+/// camera been sized to its window.
+pub(crate) fn screen_point(app: &mut App, camera: Entity, point: Vec3) -> Vec2 {
+    let (camera, transform) = app
+        .world
+        .query::<(&Camera, &GlobalTransform)>()
+        .get(&app.world, camera)
+        .unwrap();
+    camera.world_to_viewport(transform, point).unwrap()
+}
+
+/// Press or release `button` over the primary window, seen on the next update.
+pub(crate) fn send_button(app: &mut App, button: MouseButton, state: ButtonState) {
+    let window = primary_window(app);
+    app.world.send_event(MouseButtonInput {
+        button,
+        state,
+        window,
+    });
+}
+
+/// Press or release `key` over the primary window, seen on the next update.
+pub(crate) fn send_key(app: &mut App, key: KeyCode, state: ButtonState) {
+    let window = primary_window(app);
+    app.world.send_event(KeyboardInput {
+        scan_code: 0,
+        key_code: Some(key),
+        state,
+        window,
+    });
+}
+
+/// Start, move or lift the touch `id` at `position`, seen on the next update.
+pub(crate) fn send_touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+    app.world.send_event(TouchInput {
+        phase,
+        position,
+        force: None,
+        id,
+    });
+}
+
+/// Click the left mouse button at `position`, and update so it's picked.
+pub(crate) fn click_at(app: &mut App, position: Vec2) {
+    move_cursor(app, Some(position));
+    send_button(app, MouseButton::Left, ButtonState::Pressed);
+    app.update();
+    send_button(app, MouseButton::Left, ButtonState::Released);
+}
+
+/// The events of type `E` sent so far and not yet cleared.
+pub(crate) fn events<E: Event + Clone>(app: &App) -> Vec<E> {
+    let events = app.world.resource::<Events<E>>();
+    events.get_reader().iter(events).cloned().collect()
+}
+
+/// Run `system` once on the app's world and return what it returns.
+pub(crate) fn run<T: Send + Sync + 'static, M>(
+    app: &mut App,
+    system: impl IntoSystem<(), T, M>,
+) -> T {
+    let mut system = IntoSystem::into_system(system);
+    system.initialize(&mut app.world);
+    let out = system.run((), &mut app.world);
+    system.apply_deferred(&mut app.world);
+    out
+}
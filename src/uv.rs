@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use crate::Corner;
+
+/// Remaps the uvs reported for hits on a plane through a 2D affine transform, e.g. into a
+/// sub-rectangle of a texture atlas, or to rotate or shear them to match a distorted texture.
+///
+/// The matrix is applied to the uv as a homogeneous 2D point, after the plane bounds are tested,
+/// so it only changes what's reported: [`PlaneHit::uv`](crate::PlaneHit::uv) and the
+/// [`coords`](crate::PlaneHit::coords) derived from it.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intersect_plane::{MyPlane, UvTransform};
+/// fn spawn(mut commands: Commands) {
+///     // the plane shows the top right quarter of an atlas
+///     commands.spawn((
+///         MyPlane::new(2.0),
+///         UvTransform::from_rect(Rect::new(0.5, 0.0, 1.0, 0.5)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut)]
+pub struct UvTransform(pub Mat3);
+
+impl Default for UvTransform {
+    fn default() -> Self {
+        Self(Mat3::IDENTITY)
+    }
+}
+
+impl UvTransform {
+    /// Maps (0, 0) to (1, 1) onto `rect`.
+    pub fn from_rect(rect: Rect) -> Self {
+        Self(Mat3::from_scale_angle_translation(
+            rect.size(),
+            0.0,
+            rect.min,
+        ))
+    }
+
+    /// `uv` remapped through the transform.
+    pub fn apply(&self, uv: Vec2) -> Vec2 {
+        self.0.transform_point2(uv)
+    }
+}
+
+/// The pixel of a texture of `size` under `uv`, where (0, 0) is the top left pixel and pixel
+/// (x, y) covers the uvs from `(x, y) / size` up to `(x + 1, y + 1) / size`. Uvs beyond the
+/// texture clamp to its edge pixels. The inverse of [`pixel_to_uv`].
+pub fn uv_to_pixel(uv: Vec2, size: UVec2) -> UVec2 {
+    let size = size.max(UVec2::ONE);
+    (uv * size.as_vec2())
+        .floor()
+        .clamp(Vec2::ZERO, (size - 1).as_vec2())
+        .as_uvec2()
+}
+
+/// The uv at the center of `pixel` in a texture of `size`, as in [`uv_to_pixel`]. Pixels beyond
+/// the texture clamp to its edge pixels.
+pub fn pixel_to_uv(pixel: UVec2, size: UVec2) -> Vec2 {
+    let size = size.max(UVec2::ONE);
+    (pixel.min(size - 1).as_vec2() + 0.5) / size.as_vec2()
+}
+
+/// The edge of a plane a uv outside its bounds lies beyond, e.g. which edge a dragged cursor left
+/// through, for "scroll off the edge" interactions. Named like [`Corner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaneEdge {
+    /// Beyond the edge at local -X
+    Left,
+    /// Beyond the edge at local +X
+    Right,
+    /// Beyond the edge at local -Z
+    Top,
+    /// Beyond the edge at local +Z
+    Bottom,
+    /// Beyond both a horizontal and a vertical edge, diagonally off this corner
+    Corner(Corner),
+}
+
+impl PlaneEdge {
+    /// The edge `uv` lies beyond, where `uv` is laid out like `shape::Plane`'s own uvs, i.e.
+    /// (0, 0) at [`Corner::TopLeft`], or `None` if it's within the plane bounds. See
+    /// [`PlaneHit::exit_edge`](crate::PlaneHit::exit_edge) to go by where a hit really is.
+    pub fn from_uv(uv: Vec2) -> Option<Self> {
+        // which way `uv` is off the bounds along an axis, if it is
+        let side = |t: f32| (t < 0.0, t > 1.0);
+        let edge = match (side(uv.x), side(uv.y)) {
+            ((false, false), (false, false)) => return None,
+            ((true, _), (false, false)) => Self::Left,
+            ((_, true), (false, false)) => Self::Right,
+            ((false, false), (true, _)) => Self::Top,
+            ((false, false), (_, true)) => Self::Bottom,
+            ((true, _), (true, _)) => Self::Corner(Corner::TopLeft),
+            ((_, true), (true, _)) => Self::Corner(Corner::TopRight),
+            ((true, _), (_, true)) => Self::Corner(Corner::BottomLeft),
+            ((_, true), (_, true)) => Self::Corner(Corner::BottomRight),
+        };
+        Some(edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_uv_transform_is_a_no_op() {
+        let uv = Vec2::new(0.3, 0.8);
+        assert_eq!(UvTransform::default().apply(uv), uv);
+    }
+
+    #[test]
+    fn translated_uv_transform_offsets_uvs() {
+        let transform = UvTransform(Mat3::from_translation(Vec2::new(0.25, -0.5)));
+        assert_eq!(transform.apply(Vec2::new(0.5, 0.5)), Vec2::new(0.75, 0.0));
+        let atlas = UvTransform::from_rect(Rect::new(0.5, 0.0, 1.0, 0.5));
+        assert_eq!(atlas.apply(Vec2::ZERO), Vec2::new(0.5, 0.0));
+        assert_eq!(atlas.apply(Vec2::ONE), Vec2::new(1.0, 0.5));
+    }
+
+    #[test]
+    fn uv_to_pixel_corners_and_center() {
+        let size = UVec2::new(4, 2);
+        assert_eq!(uv_to_pixel(Vec2::ZERO, size), UVec2::ZERO);
+        // the far edge belongs to the last pixel
+        assert_eq!(uv_to_pixel(Vec2::ONE, size), UVec2::new(3, 1));
+        assert_eq!(uv_to_pixel(Vec2::new(1.0, 0.0), size), UVec2::new(3, 0));
+        assert_eq!(uv_to_pixel(Vec2::splat(0.5), size), UVec2::new(2, 1));
+        // beyond the texture, clamped
+        assert_eq!(uv_to_pixel(Vec2::new(-0.5, 2.0), size), UVec2::new(0, 1));
+    }
+
+    #[test]
+    fn pixel_to_uv_is_the_pixel_center() {
+        let size = UVec2::new(4, 2);
+        assert_eq!(pixel_to_uv(UVec2::ZERO, size), Vec2::new(0.125, 0.25));
+        assert_eq!(pixel_to_uv(UVec2::new(3, 1), size), Vec2::new(0.875, 0.75));
+        assert_eq!(pixel_to_uv(UVec2::new(9, 9), size), Vec2::new(0.875, 0.75));
+        for x in 0..4 {
+            for y in 0..2 {
+                let pixel = UVec2::new(x, y);
+                assert_eq!(uv_to_pixel(pixel_to_uv(pixel, size), size), pixel);
+            }
+        }
+    }
+
+    #[test]
+    fn edge_from_uv() {
+        assert_eq!(PlaneEdge::from_uv(Vec2::splat(0.5)), None);
+        // on the edge is still inside
+        assert_eq!(PlaneEdge::from_uv(Vec2::new(1.0, 0.0)), None);
+        assert_eq!(
+            PlaneEdge::from_uv(Vec2::new(-0.1, 0.5)),
+            Some(PlaneEdge::Left)
+        );
+        assert_eq!(
+            PlaneEdge::from_uv(Vec2::new(1.1, 0.5)),
+            Some(PlaneEdge::Right)
+        );
+        assert_eq!(
+            PlaneEdge::from_uv(Vec2::new(0.5, -0.1)),
+            Some(PlaneEdge::Top)
+        );
+        assert_eq!(
+            PlaneEdge::from_uv(Vec2::new(0.5, 1.1)),
+            Some(PlaneEdge::Bottom)
+        );
+    }
+
+    #[test]
+    fn edge_from_uv_off_a_corner() {
+        for (uv, corner) in [
+            (Vec2::new(-0.1, -2.0), Corner::TopLeft),
+            (Vec2::new(3.0, -0.1), Corner::TopRight),
+            (Vec2::new(-0.1, 1.1), Corner::BottomLeft),
+            (Vec2::new(1.1, 1.1), Corner::BottomRight),
+        ] {
+            assert_eq!(PlaneEdge::from_uv(uv), Some(PlaneEdge::Corner(corner)));
+        }
+    }
+}